@@ -0,0 +1,407 @@
+use std::env;
+use std::process;
+use std::time::{Duration, Instant};
+
+use aoc_2025::cli::{parse_args, Command, USAGE};
+use aoc_2025::output::{OutputFormat, PartResult};
+use aoc_2025::solution::{find, Answers};
+use aoc_2025::timing::{TimingReport, TimingRow, Timings};
+use aoc_2025::utils::answers::{AnswerStore, KnownAnswers, Verdict};
+use aoc_2025::utils::cache::InputCache;
+use aoc_2025::utils::get_input::{get_aoc_input, refresh_aoc_input};
+use aoc_2025::utils::input_check::InputCheck;
+use aoc_2025::utils::input_source::InputSource;
+use aoc_2025::utils::submit::{submit_answer, SubmissionOutcome};
+
+/// Runs, fetches, submits and verifies the solutions in the day registry.
+///
+/// Usage: `aoc <COMMAND> [OPTIONS]`; run `aoc help` for the details.
+pub fn main() {
+    let command = parse_args(env::args().skip(1)).unwrap_or_else(|err| {
+        eprintln!("{}\n\n{}", err, USAGE);
+        process::exit(2);
+    });
+
+    let result = match command {
+        Command::Run {
+            year,
+            days,
+            part,
+            input,
+            format,
+        } => run(year, &days, part, input, format),
+        Command::Fetch { year, day, refresh } => fetch(year, day, refresh),
+        Command::Submit {
+            year,
+            day,
+            part,
+            answer,
+        } => submit(year, day, part, answer),
+        Command::Record {
+            year,
+            day,
+            part,
+            answer,
+        } => record(year, day, part, answer),
+        Command::Verify {
+            year,
+            days,
+            examples: false,
+            format,
+        } => verify(year, &days, format),
+        Command::Verify {
+            year,
+            days,
+            examples: true,
+            format,
+        } => verify_examples(year, &days, format),
+        Command::Time {
+            year,
+            days,
+            repeat,
+            budget,
+            input,
+        } => time(year, &days, repeat, budget, input),
+        Command::Check {
+            year,
+            days,
+            examples,
+            input,
+        } => check(year, &days, examples, input),
+        Command::Help => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+    };
+    if let Err(err) = result {
+        eprintln!("{}", err);
+        process::exit(1);
+    }
+}
+
+/// Solves each day and prints its answers, carrying on past any day which fails.
+fn run(
+    year: u32,
+    days: &[u32],
+    part: Option<u8>,
+    input: Option<InputSource>,
+    format: OutputFormat,
+) -> Result<(), String> {
+    let parts = part.map_or(vec![1, 2], |part| vec![part]);
+    let mut failed = 0;
+    for &day in days {
+        let source = input.clone().unwrap_or(InputSource::Live { year, day });
+        let result = solve_timed(year, day, &source, part);
+        match (format, &result) {
+            (OutputFormat::Json, _) => print_json(year, day, &parts, &result, None),
+            (OutputFormat::Text, Ok((answers, _))) => match part {
+                Some(part) => println!("Day {} part {}: {}", day, part, show(answers, part)),
+                None => println!("Day {}! {}", day, answers),
+            },
+            (OutputFormat::Text, Err(err)) => eprintln!("Day {} failed: {}", day, err),
+        }
+        failed += usize::from(result.is_err());
+    }
+    match failed {
+        0 => Ok(()),
+        _ => Err(format!("{} of {} days failed", failed, days.len())),
+    }
+}
+
+fn fetch(year: u32, day: u32, refresh: bool) -> Result<(), String> {
+    let input = if refresh {
+        refresh_aoc_input(year, day)
+    } else {
+        get_aoc_input(year, day)
+    }
+    .map_err(|err| format!("Failed to fetch input: {}", err))?;
+    println!(
+        "Day {} input is {} lines, saved to {}",
+        day,
+        input.lines().count(),
+        InputCache::default().path_for(year, day).display()
+    );
+    Ok(())
+}
+
+/// Submits `answer`, or the answer the day's solution gives for its real input, and records
+/// it in the answer store if it is right.
+fn submit(year: u32, day: u32, part: u8, answer: Option<String>) -> Result<(), String> {
+    let answer = answer_or_solve(year, day, part, answer)?;
+    println!("Submitting {} for day {} part {}", answer, day, part);
+    let outcome = submit_answer(year, day, part, &answer)
+        .map_err(|err| format!("Failed to submit answer: {}", err))?;
+    println!("{}", outcome);
+    if outcome == SubmissionOutcome::Correct {
+        record(year, day, part, Some(answer))?;
+    }
+    Ok(())
+}
+
+/// Records `answer`, or the answer the day's solution gives for its real input, as the one
+/// the website accepted.
+fn record(year: u32, day: u32, part: u8, answer: Option<String>) -> Result<(), String> {
+    let answer = answer_or_solve(year, day, part, answer)?;
+    let store = AnswerStore::default();
+    store
+        .record(year, day, part, &answer)
+        .map_err(|err| format!("Failed to record answer: {}", err))?;
+    println!(
+        "Recorded {} for day {} part {} in {}",
+        answer,
+        day,
+        part,
+        store.path_for(year).display()
+    );
+    Ok(())
+}
+
+/// Solves each day which has accepted answers, and compares its answers with them. Parts
+/// with no accepted answer are not checked.
+fn verify(year: u32, days: &[u32], format: OutputFormat) -> Result<(), String> {
+    let known = AnswerStore::default()
+        .load(year)
+        .map_err(|err| format!("Failed to load accepted answers: {}", err))?;
+    let solved: Vec<u32> = days
+        .iter()
+        .copied()
+        .filter(|day| known.days().any(|known| known == *day))
+        .collect();
+    if solved.is_empty() {
+        return Err("There are no accepted answers to check against yet".to_string());
+    }
+
+    let mut failed = 0;
+    for &day in &solved {
+        let result = solve_timed(year, day, &InputSource::Live { year, day }, None);
+        let answers = match (format, &result) {
+            (OutputFormat::Json, _) => {
+                print_json(year, day, &[1, 2], &result, Some(&known));
+                result.as_ref().ok().map(|(answers, _)| answers)
+            }
+            (OutputFormat::Text, Ok((answers, _))) => {
+                for part in [1, 2] {
+                    let verdict = known.check(day, part, answers.part(part));
+                    if verdict != Verdict::Unknown {
+                        println!(
+                            "Day {} part {}: {} ({})",
+                            day,
+                            part,
+                            show(answers, part),
+                            verdict
+                        );
+                    }
+                }
+                Some(answers)
+            }
+            (OutputFormat::Text, Err(err)) => {
+                eprintln!("Day {} failed: {}", day, err);
+                None
+            }
+        };
+        let passed = answers.is_some_and(|answers| {
+            [1, 2].into_iter().all(|part| {
+                !matches!(
+                    known.check(day, part, answers.part(part)),
+                    Verdict::Wrong { .. }
+                )
+            })
+        });
+        failed += usize::from(!passed);
+    }
+    match failed {
+        0 => Ok(()),
+        _ => Err(format!("{} of {} days failed", failed, solved.len())),
+    }
+}
+
+/// Solves each day's worked example, to check that it still parses and solves.
+fn verify_examples(year: u32, days: &[u32], format: OutputFormat) -> Result<(), String> {
+    let mut failed = 0;
+    for &day in days {
+        let result = solve_timed(year, day, &InputSource::example(year, day), None);
+        match (format, &result) {
+            (OutputFormat::Json, _) => print_json(year, day, &[1, 2], &result, None),
+            (OutputFormat::Text, Ok((answers, _))) => println!("Day {} ok: {}", day, answers),
+            (OutputFormat::Text, Err(err)) => eprintln!("Day {} failed: {}", day, err),
+        }
+        failed += usize::from(result.is_err());
+    }
+    match failed {
+        0 => Ok(()),
+        _ => Err(format!("{} of {} days failed", failed, days.len())),
+    }
+}
+
+/// Solves each day `repeat` times, then prints how long each phase took. A day which fails
+/// is left out of the table.
+fn time(
+    year: u32,
+    days: &[u32],
+    repeat: usize,
+    budget: Duration,
+    input: Option<InputSource>,
+) -> Result<(), String> {
+    let mut report = TimingReport::new(Some(budget));
+    let mut failed = 0;
+    for &day in days {
+        let source = input.clone().unwrap_or(InputSource::Live { year, day });
+        match time_repeated(year, day, &source, repeat) {
+            Ok(samples) => report.push(TimingRow::new(year, day, &samples)),
+            Err(err) => {
+                eprintln!("Day {} failed: {}", day, err);
+                failed += 1;
+            }
+        }
+    }
+    print!("{}", report.render());
+    match failed {
+        0 => Ok(()),
+        _ => Err(format!("{} of {} days failed", failed, days.len())),
+    }
+}
+
+/// Reports any problems [`InputCheck`] finds with each day's input, then whether the day's
+/// solution can parse it. Only inputs which cannot be loaded or parsed count as failures,
+/// as some problems (like day 6's trailing spaces) are part of the puzzle.
+fn check(
+    year: u32,
+    days: &[u32],
+    examples: bool,
+    input: Option<InputSource>,
+) -> Result<(), String> {
+    let mut failed = 0;
+    for &day in days {
+        let source = match &input {
+            Some(source) => source.clone(),
+            None if examples => InputSource::example(year, day),
+            None => InputSource::Live { year, day },
+        };
+        let solution = find(year, day)
+            .ok_or_else(|| format!("There is no solution for {} day {}", year, day))?;
+        let input = match source.load() {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("Day {} failed: Failed to get puzzle input: {}", day, err);
+                failed += 1;
+                continue;
+            }
+        };
+
+        let issues = InputCheck::new().check(&input);
+        for issue in &issues {
+            println!("Day {}: {}", day, issue);
+        }
+        let fixable = issues
+            .iter()
+            .filter(|issue| issue.kind.is_fixable())
+            .count();
+        match solution.parse(&input) {
+            Ok(()) => println!(
+                "Day {} parses, with {} issues ({} fixable by normalising)",
+                day,
+                issues.len(),
+                fixable
+            ),
+            Err(err) => {
+                eprintln!("Day {} failed: {}", day, err);
+                failed += 1;
+            }
+        }
+    }
+    match failed {
+        0 => Ok(()),
+        _ => Err(format!("{} of {} days failed", failed, days.len())),
+    }
+}
+
+/// Loads a day's input once, then times parsing and both parts `repeat` times over it, so
+/// that an input read from stdin is still there for every run.
+fn time_repeated(
+    year: u32,
+    day: u32,
+    source: &InputSource,
+    repeat: usize,
+) -> Result<Vec<Timings>, String> {
+    let solution =
+        find(year, day).ok_or_else(|| format!("There is no solution for {} day {}", year, day))?;
+    let start = Instant::now();
+    let input = source
+        .load()
+        .map_err(|err| format!("Failed to get puzzle input: {}", err))?;
+    let load = start.elapsed();
+    (0..repeat)
+        .map(|_| {
+            let (_, timings) = solution
+                .solve_timed(&input)
+                .map_err(|err| err.to_string())?;
+            Ok(Timings { load, ..timings })
+        })
+        .collect()
+}
+
+/// Loads the input and solves `part` of a day, or both parts if it is `None`.
+fn solve_timed(
+    year: u32,
+    day: u32,
+    source: &InputSource,
+    part: Option<u8>,
+) -> Result<(Answers, Timings), String> {
+    let solution =
+        find(year, day).ok_or_else(|| format!("There is no solution for {} day {}", year, day))?;
+    let start = Instant::now();
+    let input = source
+        .load()
+        .map_err(|err| format!("Failed to get puzzle input: {}", err))?;
+    let load = start.elapsed();
+    let (answers, timings) = match part {
+        Some(part) => solution.solve_part_timed(&input, part),
+        None => solution.solve_timed(&input),
+    }
+    .map_err(|err| err.to_string())?;
+    Ok((answers, Timings { load, ..timings }))
+}
+
+fn answer_or_solve(
+    year: u32,
+    day: u32,
+    part: u8,
+    answer: Option<String>,
+) -> Result<String, String> {
+    match answer {
+        Some(answer) => Ok(answer),
+        None => solve_timed(year, day, &InputSource::Live { year, day }, Some(part))?
+            .0
+            .part(part)
+            .map(str::to_string)
+            .ok_or_else(|| format!("Day {} part {} has not been solved yet", day, part)),
+    }
+}
+
+/// Prints each of `parts` of a day as a line of JSON, checked against the accepted answers
+/// if there are any.
+fn print_json(
+    year: u32,
+    day: u32,
+    parts: &[u8],
+    result: &Result<(Answers, Timings), String>,
+    known: Option<&KnownAnswers>,
+) {
+    for &part in parts {
+        let line = match result {
+            Ok((answers, timings)) => {
+                let line = PartResult::new(year, day, part, answers, timings);
+                match known {
+                    Some(known) => line.checked(&known.check(day, part, answers.part(part))),
+                    None => line,
+                }
+            }
+            Err(err) => PartResult::failed(year, day, part, err),
+        };
+        println!("{}", line.to_json_line());
+    }
+}
+
+fn show(answers: &Answers, part: u8) -> &str {
+    answers.part(part).unwrap_or("Not done yet!")
+}
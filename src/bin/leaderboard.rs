@@ -0,0 +1,40 @@
+use std::env;
+use std::process;
+
+use aoc_2025::utils::calendar::latest_event_year;
+use aoc_2025::utils::leaderboard::{fetch_leaderboard, LEADERBOARD_ENV_VAR};
+
+/// Shows a private leaderboard.
+///
+/// Usage: `leaderboard [BOARD_ID] [YEAR]`. The board id defaults to `AOC_LEADERBOARD`, and
+/// the year to the latest event which has started.
+pub fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let board_id = args
+        .first()
+        .cloned()
+        .or_else(|| env::var(LEADERBOARD_ENV_VAR).ok())
+        .and_then(|id| id.trim().parse::<u64>().ok())
+        .unwrap_or_else(|| usage());
+    let year = match args.get(1) {
+        Some(year) => year.trim().parse::<u32>().unwrap_or_else(|_| usage()),
+        None => latest_event_year(),
+    };
+
+    match fetch_leaderboard(year, board_id) {
+        Ok(leaderboard) => print!("{}", leaderboard.render()),
+        Err(err) => {
+            eprintln!("Failed to fetch leaderboard: {}", err);
+            process::exit(1);
+        }
+    }
+}
+
+/// Prints how to use the binary and exits with the usual code for a usage error.
+fn usage() -> ! {
+    eprintln!(
+        "Usage: leaderboard [BOARD_ID] [YEAR] (or set {} to the board id)",
+        LEADERBOARD_ENV_VAR
+    );
+    process::exit(2);
+}
@@ -0,0 +1,534 @@
+use std::fmt;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::days::DAYS;
+use crate::output::OutputFormat;
+use crate::solution::find;
+use crate::utils::input_source::InputSource;
+
+/// The year used when `--year` is not given.
+pub const DEFAULT_YEAR: u32 = 2025;
+
+/// The time a day may take by default before `time` flags it.
+pub const DEFAULT_BUDGET: Duration = Duration::from_secs(1);
+
+/// How to use the `aoc` binary.
+pub const USAGE: &str = "\
+Usage: aoc <COMMAND> [OPTIONS]
+
+Commands:
+  run     Solve one day, or every day
+  fetch   Download a day's puzzle input into the cache
+  submit  Submit an answer for one part of a day, recording it if it is right
+  record  Record an answer the website has already accepted
+  verify  Check that solved days still give their accepted answers
+  time    Time loading, parsing and each part, for one day or every day
+  check   Look for problems with the input, such as tabs or Windows line endings
+  help    Show this message
+
+Options:
+  --year YEAR      The event year (default 2025)
+  --day DAY        The day to use
+  --all            Use every day with a solution (run, verify and time)
+  --examples       Use the worked examples instead (verify and check)
+  --format FORMAT  Print results as `text` (the default) or `json` lines (run and verify)
+  --part PART      Only solve or submit part 1 or 2
+  --input PATH|-   Read the input from a file, or from stdin with `-` (run, time and check)
+  --refresh        Download the input even if it is cached (fetch only)
+  --repeat N       Run each day N times, reporting the median and best (time only)
+  --budget MS      Flag days which take longer than this (time only, default 1000)
+
+`submit` and `record` take the answer as their last argument, or solve the day to get it.";
+
+/// What the `aoc` binary has been asked to do, as parsed by [`parse_args`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    /// Solve each of `days`, printing either both answers or just `part`'s. `input` is
+    /// `None` to use each day's real puzzle input.
+    Run {
+        year: u32,
+        days: Vec<u32>,
+        part: Option<u8>,
+        input: Option<InputSource>,
+        format: OutputFormat,
+    },
+    /// Download a day's puzzle input, unless it is cached and `refresh` is not set.
+    Fetch { year: u32, day: u32, refresh: bool },
+    /// Submit `answer` for one part of a day, or the day's own answer if it is `None`.
+    Submit {
+        year: u32,
+        day: u32,
+        part: u8,
+        answer: Option<String>,
+    },
+    /// Record `answer` as accepted for one part of a day, or the day's own answer if it is
+    /// `None`.
+    Record {
+        year: u32,
+        day: u32,
+        part: u8,
+        answer: Option<String>,
+    },
+    /// Solve each of `days` and compare the answers with the accepted ones, or if `examples`
+    /// is set, just check that each day solves its worked example.
+    Verify {
+        year: u32,
+        days: Vec<u32>,
+        examples: bool,
+        format: OutputFormat,
+    },
+    /// Solve each of `days` `repeat` times, and report how long each phase took, flagging
+    /// any day which takes longer than `budget`. `input` is as for [`Command::Run`].
+    Time {
+        year: u32,
+        days: Vec<u32>,
+        repeat: usize,
+        budget: Duration,
+        input: Option<InputSource>,
+    },
+    /// Check the input of each of `days` for problems, then check that it parses. `input`
+    /// is as for [`Command::Run`], or the worked examples are used if `examples` is set.
+    Check {
+        year: u32,
+        days: Vec<u32>,
+        examples: bool,
+        input: Option<InputSource>,
+    },
+    /// Show the usage message.
+    Help,
+}
+
+/// A problem with the command line, to be reported alongside [`USAGE`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UsageError(pub String);
+
+impl fmt::Display for UsageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for UsageError {}
+
+/// The options given after the command, before they are checked against what the command
+/// accepts.
+#[derive(Debug, Default)]
+struct Options {
+    year: Option<u32>,
+    day: Option<u32>,
+    all: bool,
+    examples: bool,
+    part: Option<u8>,
+    input: Option<String>,
+    format: Option<OutputFormat>,
+    refresh: bool,
+    repeat: Option<usize>,
+    budget: Option<Duration>,
+    positional: Vec<String>,
+}
+
+/// Parses the arguments given to the `aoc` binary, not including the program name.
+///
+/// Days are checked against [`DAYS`] for `run` and `verify`, so that a typo is reported
+/// before any input is fetched.
+///
+/// # Errors
+/// Returns a [`UsageError`] describing the first problem with the arguments.
+///
+/// # Example
+/// ```rust
+/// use aoc_2025::cli::{parse_args, Command};
+/// use aoc_2025::output::OutputFormat;
+/// let command = parse_args(["run", "--day", "5", "--part", "2"]).unwrap();
+/// assert_eq!(
+///     command,
+///     Command::Run {
+///         year: 2025,
+///         days: vec![5],
+///         part: Some(2),
+///         input: None,
+///         format: OutputFormat::Text,
+///     }
+/// );
+/// assert!(parse_args(["run", "--day", "13"]).is_err());
+/// ```
+pub fn parse_args<I, S>(args: I) -> Result<Command, UsageError>
+where
+    I: IntoIterator<Item = S>,
+    S: Into<String>,
+{
+    let mut args = args.into_iter().map(Into::into);
+    let command = match args.next() {
+        Some(command) => command,
+        None => return Err(UsageError("no command given".to_string())),
+    };
+    if matches!(command.as_str(), "help" | "-h" | "--help") {
+        return Ok(Command::Help);
+    }
+
+    let mut options = Options::default();
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| {
+            args.next()
+                .ok_or_else(|| UsageError(format!("{} needs a value", name)))
+        };
+        match arg.as_str() {
+            "--year" => options.year = Some(parse_number("--year", &value("--year")?)?),
+            "--day" => options.day = Some(parse_number("--day", &value("--day")?)?),
+            "--part" => {
+                let part = value("--part")?;
+                options.part = match part.as_str() {
+                    "1" => Some(1),
+                    "2" => Some(2),
+                    _ => return Err(UsageError(format!("--part must be 1 or 2, not {}", part))),
+                };
+            }
+            "--input" => options.input = Some(value("--input")?),
+            "--format" => {
+                let format = value("--format")?;
+                options.format = match format.as_str() {
+                    "text" => Some(OutputFormat::Text),
+                    "json" => Some(OutputFormat::Json),
+                    _ => {
+                        return Err(UsageError(format!(
+                            "--format must be text or json, not {}",
+                            format
+                        )))
+                    }
+                };
+            }
+            "--repeat" => match parse_number("--repeat", &value("--repeat")?)? {
+                0 => return Err(UsageError("--repeat must be at least 1".to_string())),
+                repeat => options.repeat = Some(repeat as usize),
+            },
+            "--budget" => {
+                let millis = parse_number("--budget", &value("--budget")?)?;
+                options.budget = Some(Duration::from_millis(millis.into()));
+            }
+            "--all" => options.all = true,
+            "--examples" => options.examples = true,
+            "--refresh" => options.refresh = true,
+            "-h" | "--help" => return Ok(Command::Help),
+            _ if arg.starts_with("--") => {
+                return Err(UsageError(format!("unknown option {}", arg)))
+            }
+            _ => options.positional.push(arg),
+        }
+    }
+    let year = options.year.unwrap_or(DEFAULT_YEAR);
+
+    match command.as_str() {
+        "run" => {
+            options.allow(
+                &command,
+                &["--day", "--all", "--part", "--input", "--format"],
+                0,
+            )?;
+            let days = options.solved_days(year, false)?;
+            Ok(Command::Run {
+                year,
+                input: options.input_source(&days)?,
+                days,
+                part: options.part,
+                format: options.format.unwrap_or_default(),
+            })
+        }
+        "fetch" => {
+            options.allow(&command, &["--day", "--refresh"], 0)?;
+            Ok(Command::Fetch {
+                year,
+                day: options.required_day()?,
+                refresh: options.refresh,
+            })
+        }
+        "submit" | "record" => {
+            options.allow(&command, &["--day", "--part"], 1)?;
+            let day = options.required_day()?;
+            let part = options
+                .part
+                .ok_or_else(|| UsageError("--part is needed".to_string()))?;
+            let answer = options.positional.pop();
+            Ok(match command.as_str() {
+                "submit" => Command::Submit {
+                    year,
+                    day,
+                    part,
+                    answer,
+                },
+                _ => Command::Record {
+                    year,
+                    day,
+                    part,
+                    answer,
+                },
+            })
+        }
+        "verify" => {
+            options.allow(&command, &["--day", "--all", "--examples", "--format"], 0)?;
+            let days = options.solved_days(year, true)?;
+            Ok(Command::Verify {
+                year,
+                days,
+                examples: options.examples,
+                format: options.format.unwrap_or_default(),
+            })
+        }
+        "time" => {
+            options.allow(
+                &command,
+                &["--day", "--all", "--input", "--repeat", "--budget"],
+                0,
+            )?;
+            let days = options.solved_days(year, true)?;
+            Ok(Command::Time {
+                year,
+                input: options.input_source(&days)?,
+                days,
+                repeat: options.repeat.unwrap_or(1),
+                budget: options.budget.unwrap_or(DEFAULT_BUDGET),
+            })
+        }
+        "check" => {
+            options.allow(&command, &["--day", "--all", "--examples", "--input"], 0)?;
+            if options.examples && options.input.is_some() {
+                return Err(UsageError(
+                    "--examples and --input cannot be used together".to_string(),
+                ));
+            }
+            let days = options.solved_days(year, true)?;
+            Ok(Command::Check {
+                year,
+                input: options.input_source(&days)?,
+                days,
+                examples: options.examples,
+            })
+        }
+        _ => Err(UsageError(format!("unknown command {}", command))),
+    }
+}
+
+impl Options {
+    /// Checks that only the `allowed` options (and `--year`) were given, along with at most
+    /// `positional` other arguments.
+    fn allow(&self, command: &str, allowed: &[&str], positional: usize) -> Result<(), UsageError> {
+        let given = [
+            ("--day", self.day.is_some()),
+            ("--all", self.all),
+            ("--examples", self.examples),
+            ("--part", self.part.is_some()),
+            ("--input", self.input.is_some()),
+            ("--format", self.format.is_some()),
+            ("--refresh", self.refresh),
+            ("--repeat", self.repeat.is_some()),
+            ("--budget", self.budget.is_some()),
+        ];
+        if let Some((name, _)) = given
+            .iter()
+            .find(|(name, given)| *given && !allowed.contains(name))
+        {
+            return Err(UsageError(format!(
+                "{} cannot be used with {}",
+                name, command
+            )));
+        }
+        if self.positional.len() > positional {
+            return Err(UsageError(format!(
+                "unexpected argument {}",
+                self.positional[positional]
+            )));
+        }
+        Ok(())
+    }
+
+    /// Where `--input` says to read the input from, which only makes sense for one day.
+    fn input_source(&self, days: &[u32]) -> Result<Option<InputSource>, UsageError> {
+        match self.input.as_deref() {
+            Some(_) if days.len() != 1 => Err(UsageError(
+                "--input can only be used with a single --day".to_string(),
+            )),
+            Some("-") => Ok(Some(InputSource::Stdin)),
+            Some(path) => Ok(Some(InputSource::File(PathBuf::from(path)))),
+            None => Ok(None),
+        }
+    }
+
+    fn required_day(&self) -> Result<u32, UsageError> {
+        self.day
+            .ok_or_else(|| UsageError("--day is needed".to_string()))
+    }
+
+    /// The days picked by `--day` or `--all`, which must have solutions. If neither is
+    /// given, that means every day when `all_by_default` is set, and is an error otherwise.
+    fn solved_days(&self, year: u32, all_by_default: bool) -> Result<Vec<u32>, UsageError> {
+        match (self.day, self.all) {
+            (Some(_), true) => Err(UsageError(
+                "--day and --all cannot be used together".to_string(),
+            )),
+            (Some(day), false) if find(year, day).is_some() => Ok(vec![day]),
+            (Some(day), false) => Err(UsageError(format!(
+                "there is no solution for {} day {}",
+                year, day
+            ))),
+            (None, all) if all || all_by_default => Ok(solved_days(year)),
+            (None, _) => Err(UsageError("--day or --all is needed".to_string())),
+        }
+    }
+}
+
+/// Every day of `year` with a solution, in order.
+fn solved_days(year: u32) -> Vec<u32> {
+    DAYS.iter()
+        .filter(|solution| solution.year == year)
+        .map(|solution| solution.day)
+        .collect()
+}
+
+fn parse_number(name: &str, value: &str) -> Result<u32, UsageError> {
+    value
+        .parse()
+        .map_err(|_| UsageError(format!("{} must be a number, not {}", name, value)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(
+            parse_args(["run", "--day", "5", "--part", "2", "--input", "-"]),
+            Ok(Command::Run {
+                year: 2025,
+                days: vec![5],
+                part: Some(2),
+                input: Some(InputSource::Stdin),
+                format: OutputFormat::Text,
+            })
+        );
+        assert_eq!(
+            parse_args(["run", "--all", "--format", "json"]),
+            Ok(Command::Run {
+                year: 2025,
+                days: (1..=12).collect(),
+                part: None,
+                input: None,
+                format: OutputFormat::Json,
+            })
+        );
+        assert_eq!(
+            parse_args(["fetch", "--year", "2025", "--day", "3", "--refresh"]),
+            Ok(Command::Fetch {
+                year: 2025,
+                day: 3,
+                refresh: true,
+            })
+        );
+        assert_eq!(
+            parse_args(["submit", "--day", "1", "--part", "1", "1234"]),
+            Ok(Command::Submit {
+                year: 2025,
+                day: 1,
+                part: 1,
+                answer: Some("1234".to_string()),
+            })
+        );
+        assert_eq!(
+            parse_args(["record", "--day", "5", "--part", "2"]),
+            Ok(Command::Record {
+                year: 2025,
+                day: 5,
+                part: 2,
+                answer: None,
+            })
+        );
+        assert_eq!(
+            parse_args(["verify"]),
+            Ok(Command::Verify {
+                year: 2025,
+                days: (1..=12).collect(),
+                examples: false,
+                format: OutputFormat::Text,
+            })
+        );
+        assert_eq!(
+            parse_args(["verify", "--day", "3", "--examples"]),
+            Ok(Command::Verify {
+                year: 2025,
+                days: vec![3],
+                examples: true,
+                format: OutputFormat::Text,
+            })
+        );
+        assert_eq!(
+            parse_args([
+                "time",
+                "--day",
+                "7",
+                "--input",
+                "day_7.txt",
+                "--repeat",
+                "5"
+            ]),
+            Ok(Command::Time {
+                year: 2025,
+                days: vec![7],
+                repeat: 5,
+                budget: DEFAULT_BUDGET,
+                input: Some(InputSource::File(PathBuf::from("day_7.txt"))),
+            })
+        );
+        assert_eq!(
+            parse_args(["time", "--budget", "250"]),
+            Ok(Command::Time {
+                year: 2025,
+                days: (1..=12).collect(),
+                repeat: 1,
+                budget: Duration::from_millis(250),
+                input: None,
+            })
+        );
+        assert_eq!(
+            parse_args(["check", "--examples"]),
+            Ok(Command::Check {
+                year: 2025,
+                days: (1..=12).collect(),
+                examples: true,
+                input: None,
+            })
+        );
+        assert_eq!(parse_args(["--help"]), Ok(Command::Help));
+    }
+
+    #[test]
+    fn test_parse_args_errors() {
+        for args in [
+            &[][..],
+            &["solve", "--day", "1"],
+            &["run"],
+            &["run", "--day", "1", "--all"],
+            &["run", "--day", "13"],
+            &["run", "--day", "one"],
+            &["run", "--day", "1", "--part", "3"],
+            &["run", "--all", "--input", "-"],
+            &["run", "--day", "1", "--refresh"],
+            &["run", "--day", "1", "extra"],
+            &["fetch"],
+            &["submit", "--day", "1"],
+            &["submit", "--day", "1", "--part", "1", "12", "34"],
+            &["verify", "--day"],
+            &["verify", "--input", "-"],
+            &["run", "--day", "1", "--examples"],
+            &["record", "--part", "1", "1234"],
+            &["run", "--day", "1", "--format", "yaml"],
+            &["time", "--format", "json"],
+            &["time", "--input", "-"],
+            &["time", "--repeat", "0"],
+            &["time", "--budget", "1s"],
+            &["check", "--day", "1", "--examples", "--input", "-"],
+            &["check", "--part", "1"],
+        ] {
+            assert!(parse_args(args.iter().copied()).is_err(), "{:?}", args);
+        }
+    }
+}
@@ -0,0 +1,188 @@
+use crate::solution::Solution;
+use crate::utils::parsing::{numbered_lines, ParseError};
+
+/// The solution for Advent of Code Day 1.
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<(char, i16)>;
+    type Part1 = i16;
+    type Part2 = i16;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(instructions: &Self::Input) -> Option<i16> {
+        Some(move_dial(instructions, false))
+    }
+
+    fn part2(instructions: &Self::Input) -> Option<i16> {
+        Some(move_dial(instructions, true))
+    }
+}
+
+/// Parses the movement instructions, one per line. Each instruction is a direction ('L'
+/// for left, 'R' for right) followed by a distance.
+///
+/// # Returns
+///
+/// The direction and distance of each instruction, in order.
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] if an instruction has an invalid direction or distance.
+fn parse_input(input: &str) -> Result<Vec<(char, i16)>, ParseError> {
+    numbered_lines(input)
+        .map(|(line_no, line)| {
+            let mut chars = line.chars();
+            let direction = chars
+                .next()
+                .filter(|c| *c == 'L' || *c == 'R')
+                .ok_or_else(|| {
+                    ParseError::new(line_no, line, 0..1, "expected a direction, 'L' or 'R'")
+                })?;
+            let distance = chars.as_str();
+            let how_far = distance.parse::<i16>().map_err(|_| {
+                ParseError::at_substr(line_no, line, distance, "expected a distance")
+            })?;
+            Ok((direction, how_far))
+        })
+        .collect()
+}
+
+/// Moves a dial based on a series of instructions and calculates either the number of times
+/// the dial stops at zero or the number of times it passes through zero, depending on the mode.
+///
+/// # Arguments
+///
+/// * `instructions` - The movement instructions (see [`parse_input`]): the direction
+///   ('L' for left, 'R' for right) and the distance.
+/// * `part_2` - A boolean indicating the mode of operation:
+///   - `false`: Count the number of times the dial stops at zero.
+///   - `true`: Count the number of times the dial passes through zero.
+///
+/// # Returns
+///
+/// Returns an `i16` representing the count of either stops at zero (`part_2 == false`)
+/// or passes through zero (`part_2 == true`).
+fn move_dial(instructions: &[(char, i16)], part_2: bool) -> i16 {
+    let mut current_pos: i16 = 50;
+    let mut at_zero: i16 = 0;
+    let mut pass_zero: i16 = 0;
+
+    for &(direction, how_far) in instructions {
+        let prev_pos = current_pos;
+        match direction {
+            'L' => current_pos -= how_far,
+            _ => current_pos += how_far,
+        }
+
+        if part_2 {
+            let mut passes = 0;
+            let mut pos = prev_pos;
+            let step = if direction == 'R' { 1 } else { -1 };
+
+            for _ in 0..how_far {
+                pos = (pos + step).rem_euclid(100);
+                if pos == 0 {
+                    passes += 1;
+                }
+            }
+
+            pass_zero += passes;
+        }
+
+        // wrap number around dial
+        current_pos %= 100;
+        if current_pos == 0 {
+            at_zero += 1;
+        }
+    }
+
+    if part_2 {
+        pass_zero
+    } else {
+        at_zero
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::input_source::InputSource;
+    #[test]
+    fn test_move_dial() {
+        assert_eq!(
+            move_dial(&Day1::load(&InputSource::example(2025, 1)), false),
+            3
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = parse_input("R49\nL98\nX3").unwrap_err();
+        assert_eq!((err.line, err.columns), (3, 0..1));
+        let err = parse_input("R49\nLx8").unwrap_err();
+        assert_eq!((err.line, err.columns), (2, 1..3));
+    }
+
+    #[test]
+    fn part_1_not_crossing_zero() {
+        // 50 -> 99 -> 01 without crossing zero
+        let total = move_dial(&parse_input("R49\nL98").unwrap(), false);
+        assert_eq!(total, 0);
+    }
+
+    #[test]
+    fn part_1_ending_on_zero() {
+        // 50 -> 99 -> 00 ending up at zero
+        let total = move_dial(&parse_input("R49\nR1").unwrap(), false);
+        assert_eq!(total, 1);
+    }
+
+    #[test]
+    fn part_1_all_r_stopping_at_zero_once() {
+        // 50 -> 99 -> 00 -> 01 stopping at zero once
+        let total = move_dial(&parse_input("R49\nR1\nR1").unwrap(), false);
+        assert_eq!(total, 1);
+    }
+
+    #[test]
+    fn part_1_1_l_stopping_at_zero_once() {
+        // 50 -> 01 -> 00 -> 99 stopping at zero once
+        let total = move_dial(&parse_input("R49\nR1\nL1").unwrap(), false);
+        assert_eq!(total, 1);
+    }
+
+    #[test]
+    fn part_2_pass_zero_once() {
+        // 50 -> 99 -> and a full rotation ending up at 99 again
+        let total = move_dial(&parse_input("L50\nR50").unwrap(), true);
+        assert_eq!(total, 1);
+    }
+    #[test]
+    fn part_2_pass_zero_once_2() {
+        // 50 -> 99 -> and a full rotation ending up at 99 again
+        let total = move_dial(&parse_input("L50\nL50").unwrap(), true);
+        assert_eq!(total, 1);
+    }
+    #[test]
+    fn part_2_pass_zero_twice() {
+        // 50 -> 99 -> and a full rotation ending up at 99 again
+        let total = move_dial(&parse_input("L51\nL100").unwrap(), true);
+        assert_eq!(total, 2);
+    }
+    #[test]
+    fn part_2_pass_zero_twice_2() {
+        // 50 -> 0 (via 0) -> 50
+        let total = move_dial(&parse_input("L150\nR50").unwrap(), true);
+        assert_eq!(total, 2);
+    }
+    #[test]
+    fn part_2_pass_zero_twice_3() {
+        // 50 -> 0 (via 0) -> 50
+        let total = move_dial(&parse_input("R150\nL50").unwrap(), true);
+        assert_eq!(total, 2);
+    }
+}
@@ -0,0 +1,162 @@
+use std::collections::VecDeque;
+
+use crate::solution::Solution;
+use crate::utils::parsing::{bracket_groups, numbered_lines, Bracket, ParseError};
+
+/// The solution for Advent of Code Day 10.
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Machine>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(machines: &Self::Input) -> Option<usize> {
+        machines.iter().map(Machine::fewest_presses).sum()
+    }
+
+    fn part2(_machines: &Self::Input) -> Option<usize> {
+        None
+    }
+}
+
+/// The most indicator lights a machine can have, which keeps the search over every pattern
+/// of lit lights small.
+const MAX_LIGHTS: usize = 16;
+
+/// One machine from the manual, with its lights and buttons as bit masks: bit `i` is light
+/// `i`, counting from the left.
+#[derive(Debug)]
+pub struct Machine {
+    /// The lights which should end up on. They all start off.
+    target: u32,
+    /// The lights each button toggles.
+    buttons: Vec<u32>,
+    lights: usize,
+}
+
+impl Machine {
+    /// Reads a line like `[.##.] (3) (1,3) (2) {3,5,4,7}`. The joltage requirements in
+    /// `{...}` are checked but not kept.
+    fn from_line(line_no: usize, line: &str) -> Result<Machine, ParseError> {
+        let invalid = |message: &str| ParseError::whole_line(line_no, line, message);
+        let groups = bracket_groups(line).map_err(|err| err.at_line(line_no, line))?;
+        let mut diagram = None;
+        let mut buttons = Vec::new();
+        for group in &groups {
+            match group.bracket {
+                Bracket::Square if diagram.is_none() => diagram = Some(group.contents),
+                Bracket::Square => return Err(invalid("only one light diagram is allowed")),
+                Bracket::Round => buttons.push(
+                    group
+                        .parse_items::<usize>()
+                        .map_err(|err| err.at_line(line_no, line))?,
+                ),
+                Bracket::Curly => {
+                    group
+                        .parse_items::<u32>()
+                        .map_err(|err| err.at_line(line_no, line))?;
+                }
+                Bracket::Angle => return Err(invalid("unexpected `<...>` group")),
+            }
+        }
+
+        let diagram = diagram.ok_or_else(|| invalid("missing the `[...]` light diagram"))?;
+        let lights = diagram.chars().count();
+        if lights > MAX_LIGHTS {
+            return Err(invalid(&format!(
+                "at most {} lights are supported",
+                MAX_LIGHTS
+            )));
+        }
+        let mut target = 0;
+        for (i, c) in diagram.chars().enumerate() {
+            match c {
+                '#' => target |= 1 << i,
+                '.' => {}
+                _ => return Err(invalid("lights should be `.` or `#`")),
+            }
+        }
+        let buttons = buttons
+            .into_iter()
+            .map(|wiring| {
+                wiring.into_iter().try_fold(0, |mask, light| match light {
+                    light if light < lights => Ok(mask | 1 << light),
+                    _ => Err(invalid(&format!(
+                        "a button is wired to light {}, but there are only {}",
+                        light, lights
+                    ))),
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Machine {
+            target,
+            buttons,
+            lights,
+        })
+    }
+
+    /// The fewest button presses which turn on exactly the target lights, or `None` if no
+    /// combination does.
+    ///
+    /// Pressing a button twice undoes it, so this is a breadth first search over the
+    /// `2^lights` states, each press toggling one button's lights.
+    fn fewest_presses(&self) -> Option<usize> {
+        let mut seen = vec![false; 1 << self.lights];
+        seen[0] = true;
+        let mut queue = VecDeque::from([(0u32, 0)]);
+        while let Some((state, presses)) = queue.pop_front() {
+            if state == self.target {
+                return Some(presses);
+            }
+            for button in &self.buttons {
+                let next = state ^ button;
+                if !seen[next as usize] {
+                    seen[next as usize] = true;
+                    queue.push_back((next, presses + 1));
+                }
+            }
+        }
+        None
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Machine>, ParseError> {
+    numbered_lines(input)
+        .map(|(line_no, line)| Machine::from_line(line_no, line))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::input_source::InputSource;
+    #[test]
+    fn test_day_10() {
+        let machines = Day10::load(&InputSource::example(2025, 10));
+        let presses: Vec<_> = machines.iter().map(Machine::fewest_presses).collect();
+        assert_eq!(presses, [Some(2), Some(3), Some(2)]);
+        assert_eq!(Day10::part1(&machines), Some(7));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = parse_input("[.#] (0) (2) {1}").unwrap_err();
+        assert_eq!((err.line, err.columns), (1, 0..16));
+        let err = parse_input("[.#] (0)\n[.x] (1)").unwrap_err();
+        assert_eq!(err.line, 2);
+        let err = parse_input("(0) {1}").unwrap_err();
+        assert_eq!(err.line, 1);
+        assert!(parse_input("[.#] (0) (1) {1,2}").is_ok());
+    }
+
+    #[test]
+    fn test_unreachable() {
+        let machines = parse_input("[##] (0)").unwrap();
+        assert_eq!(Day10::part1(&machines), None);
+    }
+}
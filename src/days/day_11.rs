@@ -1,5 +1,4 @@
 use rayon::prelude::*;
-use regex::Regex;
 use std::collections::hash_map::DefaultHasher;
 use std::hash::Hasher;
 use std::{
@@ -7,29 +6,47 @@ use std::{
     hash::Hash,
 };
 
-use aoc_2025::utils::{get_input::get_aoc_input, parsing::split_lines};
-
-// /// Runs the solution for Advent of Code Day 11.
-pub fn main() {
-    let mut server_rack = parse_input(11);
-    server_rack.dfs(
-        "you".to_string(),
-        "out".to_string(),
-        &mut Vec::new(),
-        &mut HashMap::new(),
-    );
-    let part_1 = server_rack.paths_so_far.len();
-    let part_2 = part_2();
-    println!("Day 11! Part 1: {:?}, Part 2: {:?}", part_1, part_2);
+use crate::scan;
+use crate::solution::Solution;
+use crate::utils::parsing::{numbered_lines, ParseError};
+
+/// The solution for Advent of Code Day 11.
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = ServerRack;
+    type Part1 = usize;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(server_rack: &Self::Input) -> Option<usize> {
+        let mut server_rack = server_rack.clone();
+        server_rack.dfs(
+            "you".to_string(),
+            "out".to_string(),
+            &mut Vec::new(),
+            &mut HashMap::new(),
+        );
+        Some(server_rack.paths_so_far.len())
+    }
+
+    fn part2(server_rack: &Self::Input) -> Option<u64> {
+        Some(part_2(server_rack))
+    }
 }
 
 #[derive(Debug, Clone)]
-struct ServerRack {
+pub struct ServerRack {
     connections: HashMap<String, HashSet<String>>,
     node_to_id: HashMap<String, u32>,
     id_to_node: HashMap<u32, String>,
     paths_so_far: HashSet<Vec<u32>>,
+    #[allow(dead_code)] // not needed by the current part 2 approach
     dac_id: Option<u32>,
+    #[allow(dead_code)] // not needed by the current part 2 approach
     fft_id: Option<u32>,
 }
 
@@ -45,6 +62,7 @@ impl ServerRack {
         }
     }
 
+    #[allow(dead_code)] // not needed by the current part 2 approach
     fn dac_id(&mut self) -> Option<u32> {
         if self.dac_id.is_none() {
             self.dac_id = Some(self.node_to_id["dac"]);
@@ -53,6 +71,7 @@ impl ServerRack {
         self.dac_id
     }
 
+    #[allow(dead_code)] // not needed by the current part 2 approach
     fn fft_id(&mut self) -> Option<u32> {
         if self.fft_id.is_none() {
             self.fft_id = Some(self.node_to_id["fft"]);
@@ -130,39 +149,35 @@ impl ServerRack {
     }
 }
 
-fn parse_input(day: u8) -> ServerRack {
-    let raw_input: Vec<String> = split_lines(get_aoc_input(2025, day as u32));
+/// Parses the list of devices, one per line in the form `aaa: bbb ccc`, where `aaa`
+/// has outputs going to `bbb` and `ccc`.
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] if a line does not start with a device name and a colon.
+fn parse_input(input: &str) -> Result<ServerRack, ParseError> {
     let mut server_rack: ServerRack = ServerRack::new();
-    let re = Regex::new(r"[a-zA-Z]{3}").unwrap();
-
-    for (id, line) in raw_input.iter().enumerate() {
-        let all_groups: Vec<String> = re
-            .find_iter(line)
-            .map(|mat| mat.as_str().to_string())
-            .collect();
-        server_rack.connections.insert(
-            all_groups[0].clone(),
-            HashSet::from_iter(all_groups[1..].to_vec()),
-        );
-        server_rack
-            .node_to_id
-            .insert(all_groups[0].clone(), id as u32);
+    for (line_no, line) in numbered_lines(input) {
+        let id = line_no - 1;
+        let (device, outputs) =
+            scan!(line, "{}: {}", String, Vec<String>).map_err(|err| err.at_line(line_no, line))?;
+        if device.len() != 3 || !device.chars().all(|c| c.is_ascii_alphabetic()) {
+            return Err(ParseError::whole_line(
+                line_no,
+                line,
+                "expected a device like `aaa: bbb ccc`",
+            ));
+        }
         server_rack
-            .id_to_node
-            .insert(id as u32, all_groups[0].clone());
+            .connections
+            .insert(device.clone(), HashSet::from_iter(outputs));
+        server_rack.node_to_id.insert(device.clone(), id as u32);
+        server_rack.id_to_node.insert(id as u32, device);
     }
-    server_rack
+    Ok(server_rack)
 }
 
-fn part_2() -> u64 {
-    let testing: bool = cfg!(test);
-    let mut svr_to_dac: ServerRack;
-    if testing {
-        svr_to_dac = parse_input(25); // saved under day 25 because part 2 has diff test input
-    } else {
-        svr_to_dac = parse_input(11);
-    }
-
+fn part_2(svr_to_dac: &ServerRack) -> u64 {
     // Clone the initial `ServerRack` for each DFS call
     let racks = vec![
         ("svr", "dac", svr_to_dac.clone()),
@@ -205,10 +220,11 @@ fn part_2() -> u64 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::utils::input_source::InputSource;
     #[test]
     fn test_day_11() {
         // assert_eq!(largest_area(), 50);
-        let mut test = parse_input(11);
+        let mut test = Day11::load(&InputSource::example(2025, 11));
         test.dfs(
             "you".to_string(),
             "out".to_string(),
@@ -216,11 +232,25 @@ mod tests {
             &mut HashMap::new(),
         );
         assert_eq!(test.paths_so_far.len(), 5);
-        println!("{:?},{:?}", parse_input(11), test.paths_so_far.len());
+        println!(
+            "{:?},{:?}",
+            Day11::load(&InputSource::example(2025, 11)),
+            test.paths_so_far.len()
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse_input("aaa: bbb\nbbb: out").is_ok());
+        let err = parse_input("aaa: bbb\nbbb out").unwrap_err();
+        assert_eq!((err.line, err.columns), (2, 0..7));
     }
 
     #[test]
     fn test_part_2() {
-        assert_eq!(part_2(), 2);
+        assert_eq!(
+            part_2(&Day11::load(&InputSource::example_part(2025, 11, 2))),
+            2
+        );
     }
 }
@@ -0,0 +1,354 @@
+use std::collections::HashMap;
+
+use crate::scan;
+use crate::solution::Solution;
+use crate::utils::parsing::{art_blocks, ParseError};
+
+/// The solution for Advent of Code Day 12.
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = PresentsAndTrees;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(presents_and_trees: &Self::Input) -> Option<u32> {
+        Some(can_they_fit(presents_and_trees))
+    }
+
+    fn part2(_presents_and_trees: &Self::Input) -> Option<u32> {
+        None
+    }
+}
+
+/// The (row, col) coordinates of every cell of a present, in one particular orientation.
+type Shape = Vec<(u32, u32)>;
+
+#[derive(Debug)]
+pub struct PresentsAndTrees {
+    present_shapes: HashMap<i32, Present>,
+    regions_under_trees: Vec<TreeRegion>,
+}
+
+#[derive(Debug)]
+struct TreeRegion {
+    region_dimensions: (u32, u32),
+    presents_required: HashMap<u32, u32>,
+    total_area: u32,
+}
+
+impl TreeRegion {
+    fn new(region_dimensions: (u32, u32), presents_required: HashMap<u32, u32>) -> TreeRegion {
+        TreeRegion {
+            region_dimensions,
+            presents_required,
+            total_area: region_dimensions.0 * region_dimensions.1,
+        }
+    }
+
+    /// Checks whether every required present can be packed into this region, trying every
+    /// rotation and flip of each shape.
+    ///
+    /// Works through the region cell by cell: each empty cell is either covered by a present
+    /// whose first cell lands on it, or left empty, as long as there is spare area left to
+    /// waste.
+    fn can_pack(&self, present_shapes: &HashMap<i32, Present>) -> bool {
+        let (width, height) = self.region_dimensions;
+        let mut remaining: Vec<(Vec<Shape>, u32)> = self
+            .presents_required
+            .iter()
+            .map(|(present, quantity)| {
+                (present_shapes[&(*present as i32)].orientations(), *quantity)
+            })
+            .collect();
+        let area_needed: u32 = self
+            .presents_required
+            .iter()
+            .map(|(present, quantity)| present_shapes[&(*present as i32)].total_area * quantity)
+            .sum();
+        if area_needed > self.total_area {
+            return false;
+        }
+        // Every orientation of every shape is tried, so the region can be turned on its side
+        // for free. Scanning along the shorter side keeps the search much smaller.
+        let (long_side, short_side) = (width.max(height), width.min(height));
+        let mut filled = vec![vec![false; short_side as usize]; long_side as usize];
+
+        pack_from(
+            &mut filled,
+            0,
+            self.total_area - area_needed,
+            &mut remaining,
+        )
+    }
+}
+
+/// Recursive helper for [`TreeRegion::can_pack`], starting from cell number `cell`
+/// (counting row by row) with `slack` cells which may still be left empty.
+fn pack_from(
+    filled: &mut Vec<Vec<bool>>,
+    cell: usize,
+    slack: u32,
+    remaining: &mut Vec<(Vec<Shape>, u32)>,
+) -> bool {
+    if remaining.iter().all(|(_, quantity)| *quantity == 0) {
+        return true;
+    }
+    let width = filled[0].len();
+    let Some(cell) = (cell..filled.len() * width).find(|c| !filled[c / width][c % width]) else {
+        return false;
+    };
+    let (row, col) = ((cell / width) as i64, (cell % width) as i64);
+
+    for shape in 0..remaining.len() {
+        if remaining[shape].1 == 0 {
+            continue;
+        }
+        for orientation in remaining[shape].0.clone() {
+            // Line the first cell of the shape up with the current cell
+            let (anchor_r, anchor_c) = orientation[0];
+            let placed: Vec<(usize, usize)> = orientation
+                .iter()
+                .map(|(r, c)| {
+                    (
+                        row + *r as i64 - anchor_r as i64,
+                        col + *c as i64 - anchor_c as i64,
+                    )
+                })
+                .filter(|(r, c)| *r >= 0 && *c >= 0 && (*c as usize) < width)
+                .map(|(r, c)| (r as usize, c as usize))
+                .filter(|(r, c)| *r < filled.len() && !filled[*r][*c])
+                .collect();
+            if placed.len() != orientation.len() {
+                continue;
+            }
+
+            placed.iter().for_each(|(r, c)| filled[*r][*c] = true);
+            remaining[shape].1 -= 1;
+            let packed = pack_from(filled, cell + 1, slack, remaining);
+            remaining[shape].1 += 1;
+            placed.iter().for_each(|(r, c)| filled[*r][*c] = false);
+            if packed {
+                return true;
+            }
+        }
+    }
+
+    if slack > 0 {
+        // Leave this cell empty
+        filled[row as usize][col as usize] = true;
+        let packed = pack_from(filled, cell + 1, slack - 1, remaining);
+        filled[row as usize][col as usize] = false;
+        return packed;
+    }
+
+    false
+}
+#[derive(Debug)]
+struct Present {
+    #[allow(dead_code)] // only used for debugging output
+    id: i32,
+    coords: Vec<(u32, u32)>,
+    dimensions: (u32, u32),
+    #[allow(dead_code)] // only used for debugging output
+    outline_area: u32,
+    total_area: u32,
+}
+
+impl Present {
+    fn new(id: i32, coords: Vec<(u32, u32)>) -> Present {
+        let total_area = coords.len() as u32;
+        let dimensions = Present::shape_dimensions(&coords);
+        Present {
+            id,
+            coords,
+            dimensions,
+            outline_area: dimensions.0 * dimensions.1,
+            total_area,
+        }
+    }
+
+    fn shape_dimensions(coords: &[(u32, u32)]) -> (u32, u32) {
+        let min_r: u32 = coords.iter().map(|(r, _)| *r).min().unwrap();
+        let max_r: u32 = coords.iter().map(|(r, _)| *r).max().unwrap();
+        let min_c: u32 = coords.iter().map(|(_, c)| *c).min().unwrap();
+        let max_c: u32 = coords.iter().map(|(_, c)| *c).max().unwrap();
+
+        let height = max_r - min_r + 1; // y_up
+        let width = max_c - min_c + 1; // x_across
+
+        (width, height)
+    }
+
+    /// Returns every distinct rotation and flip of this present's shape, each moved to the
+    /// top left corner and sorted row by row.
+    fn orientations(&self) -> Vec<Shape> {
+        let mut orientations: Vec<Shape> = Vec::new();
+        let mut shape: Vec<(i64, i64)> = self
+            .coords
+            .iter()
+            .map(|(r, c)| (*r as i64, *c as i64))
+            .collect();
+        for flip in 0..2 {
+            if flip == 1 {
+                shape = shape.iter().map(|(r, c)| (*r, -c)).collect();
+            }
+            for _ in 0..4 {
+                shape = shape.iter().map(|(r, c)| (*c, -r)).collect(); // rotate 90 degrees
+                let min_r = shape.iter().map(|(r, _)| *r).min().unwrap();
+                let min_c = shape.iter().map(|(_, c)| *c).min().unwrap();
+                let mut normalised: Shape = shape
+                    .iter()
+                    .map(|(r, c)| ((r - min_r) as u32, (c - min_c) as u32))
+                    .collect();
+                normalised.sort();
+                if !orientations.contains(&normalised) {
+                    orientations.push(normalised);
+                }
+            }
+        }
+
+        orientations
+    }
+}
+
+/// Parses the present shapes (a header like "0:" followed by a diagram of '#'s and '.'s)
+/// and the regions under the trees (lines like "4x4: 0 0 0 0 2 0").
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] if a shape diagram is malformed or empty, there are regions
+/// but no shapes, or a region line does not have its dimensions or asks for presents with
+/// no shape.
+fn parse_input(input: &str) -> Result<PresentsAndTrees, ParseError> {
+    let mut patterns: HashMap<i32, Present> = HashMap::new();
+    let mut numeric_lines: Vec<TreeRegion> = Vec::new();
+
+    let art = art_blocks(input, '#', '.')?;
+
+    // Present shapes have a header like "0:" or "1:"
+    for block in art.blocks {
+        let header = format!("{}:", block.label);
+        let idx = block.label.parse::<i32>().map_err(|_| {
+            ParseError::whole_line(block.line, &header, "expected a present number")
+        })?;
+        if block.cells.is_empty() {
+            return Err(ParseError::whole_line(
+                block.line,
+                &header,
+                "present shape has no '#' cells",
+            ));
+        }
+        let coords: Vec<(u32, u32)> = block
+            .cells
+            .into_iter()
+            .map(|(row, col)| (row as u32, col as u32))
+            .collect();
+        patterns.insert(idx, Present::new(idx, coords));
+    }
+
+    // Everything else is regions, one per line like: "4x4: 0 0 0 0 2 0"
+    for (line_no, line) in art.other_lines {
+        if patterns.is_empty() {
+            return Err(ParseError::whole_line(
+                line_no,
+                line,
+                "expected present shapes before the regions",
+            ));
+        }
+        let (width, height, counts) = scan!(line, "{}x{}: {}", u32, u32, Vec<u32>)
+            .map_err(|err| err.at_line(line_no, line))?;
+        let region_dimensions = (width, height);
+        let mut presents_required: HashMap<u32, u32> = HashMap::new();
+
+        for (idx, val) in counts.into_iter().enumerate() {
+            if val != 0 {
+                if !patterns.contains_key(&(idx as i32)) {
+                    return Err(ParseError::whole_line(
+                        line_no,
+                        line,
+                        format!("there is no present shape {}", idx),
+                    ));
+                }
+                presents_required.insert(idx as u32, val);
+            }
+        }
+        numeric_lines.push(TreeRegion::new(region_dimensions, presents_required));
+    }
+
+    Ok(PresentsAndTrees {
+        present_shapes: patterns,
+        regions_under_trees: numeric_lines,
+    })
+}
+
+fn can_they_fit(input: &PresentsAndTrees) -> u32 {
+    let mut possible_trees = 0;
+    for tree in input.regions_under_trees.iter() {
+        let mut area_taken_up_so_far = 0;
+        for (present, quantity) in tree.presents_required.iter() {
+            // Check if total space of gifts can feasibly fit under tree,
+            // without bothering rotation so far
+            let total_present_area = input.present_shapes[&(*present as i32)].total_area * quantity;
+            area_taken_up_so_far += total_present_area;
+        }
+
+        if area_taken_up_so_far > tree.total_area {
+            continue; // Can't possibly fit
+        }
+
+        // If every present fits in its own box side by side there's no need to search
+        let (width, height) = tree.region_dimensions;
+        let box_width = input
+            .present_shapes
+            .values()
+            .map(|p| p.dimensions.0)
+            .max()
+            .expect("parse_input rejects regions without any shapes");
+        let box_height = input
+            .present_shapes
+            .values()
+            .map(|p| p.dimensions.1)
+            .max()
+            .expect("parse_input rejects regions without any shapes");
+        let num_presents: u32 = tree.presents_required.values().sum();
+        if num_presents <= (width / box_width) * (height / box_height)
+            || tree.can_pack(&input.present_shapes)
+        {
+            possible_trees += 1
+        }
+    }
+    possible_trees
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::input_source::InputSource;
+    #[test]
+    fn test_day_12() {
+        // assert_eq!(largest_area(), 50);
+        let test = Day12::load(&InputSource::example(2025, 12));
+        println!("{:?}", test);
+        assert_eq!(
+            can_they_fit(&Day12::load(&InputSource::example(2025, 12))),
+            2
+        )
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = parse_input("0:\n##\n#x\n\n2x2: 1").unwrap_err();
+        assert_eq!((err.line, err.columns), (3, 1..2));
+        let err = parse_input("0:\n##\n\n2x2: 0 1").unwrap_err();
+        assert_eq!(err.line, 4);
+        let err = parse_input("0:\r\n##\r\n#x \r\n  \r\n2x2: 1\r\n").unwrap_err();
+        assert_eq!((err.line, err.columns), (3, 1..2));
+        let err = parse_input("4x4: 0\n2x2: 0").unwrap_err();
+        assert_eq!((err.line, err.columns), (1, 0..6));
+    }
+}
@@ -1,36 +1,53 @@
-use aoc_2025::utils::{get_input::get_aoc_input, parsing::split_string_by_specified_char};
 use std::collections::HashSet;
+use std::ops::RangeInclusive;
 
-/// Runs the solution for Advent of Code Day 2.
+use crate::solution::Solution;
+use crate::utils::parsing::{ints_pair, numbered_lines, ParseError};
+
+/// The solution for Advent of Code Day 2.
 ///
 /// Computes both Part 1 and Part 2 by searching for invalid IDs according
-/// to two different rule sets. Prints the results and returns them as a tuple.
-///
-/// # Returns
-/// A tuple `(part_1, part_2)` where each value is the sum of all invalid IDs
-/// detected for the corresponding part.
-pub fn main() {
-    let part_1 = find_all_invalid_ids(1);
-    let part_2 = find_all_invalid_ids(2);
-    println!("Day 2! Part 1: {:?}, part 2: {:?}", part_1, part_2);
+/// to two different rule sets.
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<RangeInclusive<u64>>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(ranges: &Self::Input) -> Option<u64> {
+        Some(find_all_invalid_ids(ranges, 1))
+    }
+
+    fn part2(ranges: &Self::Input) -> Option<u64> {
+        Some(find_all_invalid_ids(ranges, 2))
+    }
 }
 
-/// Parses a numeric range of the form `"start-end"` into a `Range<u64>`.
-///
-/// The returned range is **inclusive**, meaning the upper bound is
-/// incremented by 1 to support Rust's half-open `start..end` syntax.
+/// Parses the comma-separated numeric ranges of the form `"start-end"`.
 ///
 /// # Parameters
-/// * `range_str` – A string slice containing the range, e.g. `"5-10"`.
-///
-/// # Panics
-/// Panics if the input is malformed or cannot be parsed into integers.
-fn parse_range(range_str: &str) -> std::ops::RangeInclusive<u64> {
-    let parts: Vec<&str> = range_str.split('-').collect();
-    let start: u64 = parts[0].trim_end().parse().expect("Invalid start of range");
-    let end: u64 = parts[1].trim_end().parse().expect("Invalid end of range");
+/// * `input` – The puzzle input, e.g. `"11-22,95-115"`. Line breaks are allowed between
+///   ranges.
+///
+/// # Errors
+/// Returns a [`ParseError`] if a range is not two integers separated by a dash.
+fn parse_input(input: &str) -> Result<Vec<RangeInclusive<u64>>, ParseError> {
+    let mut ranges = Vec::new();
+    for (line_no, line) in numbered_lines(input) {
+        for range_str in line.split(',').filter(|range| !range.trim().is_empty()) {
+            let (start, end) = ints_pair::<u64>(range_str).map_err(|_| {
+                ParseError::at_substr(line_no, line, range_str, "expected a range like 11-22")
+            })?;
+            ranges.push(start..=end);
+        }
+    }
 
-    start..=end
+    Ok(ranges)
 }
 
 /// Checks whether the first half of a string is exactly equal to the second half.
@@ -97,14 +114,13 @@ fn find_repeats_in_num(num: String) -> Option<u64> {
 /// - **Part 2:** Also includes numbers that are *any length repeating pattern*.
 ///
 /// # Arguments
-/// * `range_str` – A string like `"1000-2000"` defining the search range.
+/// * `range` – The inclusive range of numbers to search.
 /// * `part` – The puzzle part (`1` or `2`) determining which rules apply.
 ///
 /// # Returns
 /// A `HashSet<u64>` containing all numbers in the range that meet the criteria.
 ///
-fn check_range_for_repeats(range_str: &str, part: u8) -> HashSet<u64> {
-    let range = parse_range(range_str);
+fn check_range_for_repeats(range: RangeInclusive<u64>, part: u8) -> HashSet<u64> {
     let mut results: HashSet<u64> = HashSet::new();
     let mut part_2: HashSet<u64> = HashSet::new();
     for x in range {
@@ -127,21 +143,20 @@ fn check_range_for_repeats(range_str: &str, part: u8) -> HashSet<u64> {
 /// all invalid IDs found according to the specified puzzle part.
 ///
 /// This function is responsible for:
-/// - Splitting the input into ranges
 /// - Applying repeat-pattern checking
 /// - Collecting all unique invalid IDs
 /// - Summing them into a final answer
 ///
 /// # Arguments
+/// * `ranges` – The ranges to search (see [`parse_input`]).
 /// * `part` – Either `1` or `2`, selecting the validation rule set.
 ///
 /// # Returns
 /// A `u64` representing the sum of all invalid IDs.
-fn find_all_invalid_ids(part: u8) -> u64 {
-    let input = split_string_by_specified_char(get_aoc_input(2025, 2), ",");
+fn find_all_invalid_ids(ranges: &[RangeInclusive<u64>], part: u8) -> u64 {
     let mut total: HashSet<u64> = HashSet::new();
-    for item in input {
-        let results = check_range_for_repeats(&item, part);
+    for range in ranges {
+        let results = check_range_for_repeats(range.clone(), part);
         total.extend(results);
     }
 
@@ -151,26 +166,40 @@ fn find_all_invalid_ids(part: u8) -> u64 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::utils::input_source::InputSource;
     #[test]
     fn test_day_2() {
-        assert_eq!(find_all_invalid_ids(1), 1227775554);
-        assert_eq!(find_all_invalid_ids(2), 4174379265);
+        assert_eq!(
+            find_all_invalid_ids(&Day2::load(&InputSource::example(2025, 2)), 1),
+            1227775554
+        );
+        assert_eq!(
+            find_all_invalid_ids(&Day2::load(&InputSource::example(2025, 2)), 2),
+            4174379265
+        );
     }
     #[test]
     fn test_check_range_for_repeats() {
-        assert_eq!(check_range_for_repeats("11-22", 1), HashSet::from([11, 22]));
+        assert_eq!(check_range_for_repeats(11..=22, 1), HashSet::from([11, 22]));
     }
     #[test]
     fn test_check_range_for_repeats_2() {
-        assert_eq!(check_range_for_repeats("2-17", 1), HashSet::from([11]));
+        assert_eq!(check_range_for_repeats(2..=17, 1), HashSet::from([11]));
     }
     #[test]
     fn test_find_repeats_in_num() {
-        assert_eq!(check_range_for_repeats("11-12", 2), HashSet::from([11]));
+        assert_eq!(check_range_for_repeats(11..=12, 2), HashSet::from([11]));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(parse_input("11-22,\n95-115").unwrap(), [11..=22, 95..=115]);
+        let err = parse_input("11-22,95,998-1012").unwrap_err();
+        assert_eq!((err.line, err.columns), (1, 6..8));
     }
 
     #[test]
     fn test_find_repeats_in_num_2() {
-        assert_eq!(check_range_for_repeats("111-114", 2), HashSet::from([111]));
+        assert_eq!(check_range_for_repeats(111..=114, 2), HashSet::from([111]));
     }
 }
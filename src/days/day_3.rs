@@ -1,18 +1,56 @@
 use std::collections::VecDeque;
 
-use aoc_2025::utils::{get_input::get_aoc_input, parsing::split_lines};
+use crate::solution::Solution;
+use crate::utils::parsing::{numbered_lines, ParseError};
 
-/// Runs the solution for Advent of Code Day 3.
-pub fn main() {
-    let part_1 = find_total_joltage(true);
-    let part_2 = find_total_joltage(false);
-    println!("Day 3! Part 1: {:?}, part 2: {:?}", part_1, part_2);
+/// The solution for Advent of Code Day 3.
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<String>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(banks: &Self::Input) -> Option<u64> {
+        Some(find_total_joltage(banks, true))
+    }
+
+    fn part2(banks: &Self::Input) -> Option<u64> {
+        Some(find_total_joltage(banks, false))
+    }
+}
+
+/// Parses the banks of batteries, one per line, each a string of digits giving the
+/// joltage of every battery in the bank.
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] pointing at the first character which is not a digit.
+fn parse_input(input: &str) -> Result<Vec<String>, ParseError> {
+    numbered_lines(input)
+        .map(
+            |(line_no, line)| match line.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+                Some((col, c)) => Err(ParseError::new(
+                    line_no,
+                    line,
+                    col..col + 1,
+                    format!("expected a digit, not {:?}", c),
+                )),
+                None => Ok(line.to_string()),
+            },
+        )
+        .collect()
 }
 
 /// Finds the total joltage based on the input data and the specified part of the problem.
 ///
 /// # Arguments
 ///
+/// * `banks` - The banks of batteries (see [`parse_input`]).
 /// * `part_1` - A boolean indicating whether to calculate the result for part 1 (`true`)
 ///   or part 2 (`false`) of the problem.
 ///
@@ -22,19 +60,17 @@ pub fn main() {
 ///
 /// # Details
 ///
-/// This function processes the input data by splitting it into lines and then iterating
-/// over each line. Depending on the value of `part_1`, it either calculates the largest
+/// This function iterates over each bank. Depending on the value of `part_1`, it either calculates the largest
 /// two-digit number (`find_largest_number`) or the largest number of a specified length
 /// (`find_largest_number_variable_length`) for each line. The results are summed up and
 /// returned.
-fn find_total_joltage(part_1: bool) -> u64 {
-    let input: Vec<String> = split_lines(get_aoc_input(2025, 3));
+fn find_total_joltage(banks: &[String], part_1: bool) -> u64 {
     let mut all_nums: VecDeque<u64> = VecDeque::new();
-    for num in input {
+    for num in banks {
         if part_1 {
-            all_nums.push_front(find_largest_number(num));
+            all_nums.push_front(find_largest_number(num.clone()));
         } else {
-            all_nums.push_front(find_largest_number_variable_length(num, 12));
+            all_nums.push_front(find_largest_number_variable_length(num.clone(), 12));
         }
     }
 
@@ -100,9 +136,18 @@ fn find_largest_number_variable_length(num: String, target_len: usize) -> u64 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::utils::input_source::InputSource;
     #[test]
     fn test_day_3() {
-        assert_eq!(find_total_joltage(true), 357);
+        assert_eq!(
+            find_total_joltage(&Day3::load(&InputSource::example(2025, 3)), true),
+            357
+        );
+    }
+    #[test]
+    fn test_parse_errors() {
+        let err = parse_input("987\n81 1").unwrap_err();
+        assert_eq!((err.line, err.columns), (2, 2..3));
     }
     #[test]
     fn test_check_range_for_repeats() {
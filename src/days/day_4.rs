@@ -0,0 +1,122 @@
+use crate::solution::Solution;
+use crate::utils::{
+    grid::{Grid, Pos},
+    parsing::ParseError,
+};
+
+/// The solution for Advent of Code Day 4.
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Grid<bool>;
+    type Part1 = usize;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(roll_map: &Self::Input) -> Option<usize> {
+        Some(find_total_accessible_rolls(roll_map).len())
+    }
+
+    fn part2(roll_map: &Self::Input) -> Option<u32> {
+        Some(remove_rolls_as_you_go(roll_map.clone()))
+    }
+}
+
+/// Parses the input for Day 4 into a map of where the rolls ('@') are.
+///
+/// # Returns
+///
+/// A `Grid` which is `true` wherever there is a roll.
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] if the map is not rectangular, or has anything other than
+/// '@' and '.' in it.
+fn parse_input(input: &str) -> Result<Grid<bool>, ParseError> {
+    Grid::try_parse(input, |c| match c {
+        '@' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })
+}
+
+/// Finds all "accessible" rolls in the provided roll map.
+/// A roll is considered accessible if it has fewer than 4 adjacent rolls (including diagonals).
+///
+/// # Arguments
+///
+/// * `roll_map` - A reference to the map of rolls.
+///
+/// # Returns
+///
+/// A vector of (row, col) positions for all accessible rolls.
+fn find_total_accessible_rolls(roll_map: &Grid<bool>) -> Vec<Pos> {
+    roll_map
+        .find_all(&true)
+        .filter(|roll| {
+            roll_map
+                .neighbours8(*roll)
+                .filter(|neighbour| roll_map[*neighbour])
+                .count()
+                < 4
+        })
+        .collect()
+}
+
+/// Iteratively removes all accessible rolls from the roll map until no more can be removed,
+/// and returns the total number of rolls removed.
+///
+/// A roll is considered "accessible" if it has fewer than 4 adjacent rolls (including diagonals).
+/// In each iteration, all currently accessible rolls are removed from the map. The process
+/// repeats until no new accessible rolls can be found.
+///
+/// # Arguments
+///
+/// * `roll_map` - The map of rolls, which is used up as rolls are removed.
+///
+/// # Returns
+///
+/// * `u32` - The total number of rolls that were removed from the map.
+///
+/// # Panics
+///
+/// Panics if the number of removed rolls cannot be converted to `u32`.
+fn remove_rolls_as_you_go(mut roll_map: Grid<bool>) -> u32 {
+    let mut removed_rolls: usize = 0;
+    loop {
+        let touched = find_total_accessible_rolls(&roll_map);
+        if touched.is_empty() {
+            // We've maxed out all the rolls we can touch
+            break;
+        }
+        removed_rolls += touched.len();
+        for roll in touched {
+            roll_map[roll] = false;
+        }
+    }
+
+    u32::try_from(removed_rolls).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::input_source::InputSource;
+    #[test]
+    fn test_day_3() {
+        assert_eq!(
+            find_total_accessible_rolls(&Day4::load(&InputSource::example(2025, 4))).len(),
+            13
+        );
+    }
+    #[test]
+    fn test_day_3_part_2() {
+        assert_eq!(
+            remove_rolls_as_you_go(Day4::load(&InputSource::example(2025, 4))),
+            43
+        );
+    }
+}
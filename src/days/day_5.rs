@@ -1,22 +1,33 @@
 use std::{cmp::max, collections::HashSet, ops::RangeInclusive};
 
-use aoc_2025::utils::{
-    get_input::get_aoc_input, parsing::split_lines, parsing::split_string_by_specified_char,
-};
-
-/// Runs the solution for Advent of Code Day 5.
-pub fn main() {
-    let part_1 = num_valid_ingredients();
-    let part_2 = parse_input();
-    println!(
-        "Day 5! Part 1: {:?}, Part 2: {:?}",
-        part_1, part_2.current_num_valid_ids
-    );
+use crate::solution::Solution;
+use crate::utils::parsing::{ints_pair, sections, ParseError, Section};
+
+/// The solution for Advent of Code Day 5.
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = KitchenInventory;
+    type Part1 = usize;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(inventory: &Self::Input) -> Option<usize> {
+        Some(num_valid_ingredients(inventory))
+    }
+
+    fn part2(inventory: &Self::Input) -> Option<u64> {
+        Some(inventory.current_num_valid_ids)
+    }
 }
 
 /// Represents the kitchen inventory, including valid ingredient ranges, ingredient IDs,
 /// validated ingredients, and the current number of valid IDs.
-struct KitchenInventory {
+#[derive(Debug, Clone)]
+pub struct KitchenInventory {
     valid_ranges: Vec<RangeInclusive<u64>>,
     ingredient_ids: HashSet<u64>,
     validated_ingredients: HashSet<u64>,
@@ -106,51 +117,66 @@ impl KitchenInventory {
 ///
 /// A `KitchenInventory` struct with parsed ranges and ingredient IDs.
 ///
-/// # Panics
+/// # Errors
 ///
-/// Panics if the input cannot be parsed as expected.
-fn parse_input() -> KitchenInventory {
-    let input: Vec<String> = split_string_by_specified_char(get_aoc_input(2025, 5), "\n\n");
+/// Returns a [`ParseError`] if a range or ingredient ID is malformed.
+fn parse_input(input: &str) -> Result<KitchenInventory, ParseError> {
     let mut ingredients_parsed = KitchenInventory {
         valid_ranges: Vec::new(),
         ingredient_ids: HashSet::new(),
         validated_ingredients: HashSet::new(),
         current_num_valid_ids: 0,
     };
-    for range in split_lines(input[0].clone()).iter() {
-        let nums: Vec<u64> = range
-            .split("-")
-            .map(|num| {
-                num.parse::<u64>()
-                    .expect("Failed to convert range to numbers")
-            })
-            .collect();
-        ingredients_parsed.add_range(RangeInclusive::new(nums[0], nums[1]));
+    let sections: Vec<Section> = sections(input).collect();
+    let (ranges, ids) = match sections.as_slice() {
+        [ranges, ids] => (ranges, ids),
+        [_, _, extra, ..] => {
+            return Err(ParseError::whole_line(
+                extra.start_line,
+                extra.lines.first().copied().unwrap_or_default(),
+                "expected only the ranges and the ingredient IDs",
+            ))
+        }
+        _ => {
+            let line_no = input.lines().count();
+            let last = input.lines().last().unwrap_or_default();
+            return Err(ParseError::whole_line(
+                line_no,
+                last,
+                "expected a blank line then the ingredient IDs",
+            ));
+        }
+    };
+
+    for (line_no, range) in ranges.numbered_lines() {
+        let (start, end) = ints_pair::<u64>(range).map_err(|err| err.at_line(line_no, range))?;
+        ingredients_parsed.add_range(RangeInclusive::new(start, end));
     }
 
     ingredients_parsed.aggregate_ranges(); // this is key for part 2!
 
-    let ingredient_ids: Vec<u64> = split_lines(input[1].clone())
-        .into_iter()
-        .map(|num| {
-            num.parse::<u64>()
-                .expect("Failed to convert IDs to numbers")
-        })
-        .collect();
-    for id in ingredient_ids {
+    for (line_no, id) in ids.numbered_lines() {
+        let id = id
+            .trim()
+            .parse::<u64>()
+            .map_err(|_| ParseError::whole_line(line_no, id, "expected an ingredient ID"))?;
         ingredients_parsed.ingredient_ids.insert(id);
     }
 
-    ingredients_parsed
+    Ok(ingredients_parsed)
 }
 
 /// Returns the number of ingredient IDs that are valid according to the parsed ranges.
 ///
+/// # Arguments
+///
+/// * `inventory` - The ranges and ingredient IDs (see [`parse_input`]).
+///
 /// # Returns
 ///
 /// The number of valid ingredient IDs (`usize`).
-fn num_valid_ingredients() -> usize {
-    let mut ingredients_parsed: KitchenInventory = parse_input();
+fn num_valid_ingredients(inventory: &KitchenInventory) -> usize {
+    let mut ingredients_parsed: KitchenInventory = inventory.clone();
     ingredients_parsed.validate_ingredients();
 
     ingredients_parsed.validated_ingredients.len()
@@ -159,18 +185,45 @@ fn num_valid_ingredients() -> usize {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::utils::input_source::InputSource;
     #[test]
     fn test_day_5() {
-        assert_eq!(num_valid_ingredients(), 3);
+        assert_eq!(
+            num_valid_ingredients(&Day5::load(&InputSource::example(2025, 5))),
+            3
+        );
     }
 
     #[test]
     fn test_day_5_part_2() {
-        assert_eq!(parse_input().current_num_valid_ids, 14);
+        assert_eq!(
+            Day5::load(&InputSource::example(2025, 5)).current_num_valid_ids,
+            14
+        );
+    }
+    #[test]
+    fn test_parse_errors() {
+        let err = parse_input("3-5\n10\n\n1").unwrap_err();
+        assert_eq!((err.line, err.columns), (2, 0..2));
+        let err = parse_input("3-5\n\n1\n2x").unwrap_err();
+        assert_eq!((err.line, err.columns), (4, 0..2));
+        let err = parse_input("3-5\n10-14\n").unwrap_err();
+        assert_eq!(err.line, 2);
     }
+
+    #[test]
+    fn test_parse_windows_line_endings() {
+        let input = InputSource::example(2025, 5).load().unwrap();
+        let windows = input.replace('\n', " \r\n");
+        let unix = parse_input(&input).unwrap();
+        let windows = parse_input(&windows).unwrap();
+        assert_eq!(windows.valid_ranges, unix.valid_ranges);
+        assert_eq!(windows.ingredient_ids, unix.ingredient_ids);
+    }
+
     #[test]
     fn test_day_5_part_2_extra_test() {
-        let mut test = parse_input();
+        let mut test = Day5::load(&InputSource::example(2025, 5));
         test.add_range(RangeInclusive::new(20, 30));
         test.aggregate_ranges();
         assert_eq!(test.current_num_valid_ids, 24);
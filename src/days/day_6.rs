@@ -0,0 +1,255 @@
+use crate::solution::Solution;
+use crate::utils::parsing::{column_groups, numbered_lines, ParseError, ReadDirection};
+
+/// The solution for Advent of Code Day 6.
+pub struct Day6;
+
+impl Solution for Day6 {
+    /// The maths sheet read row-wise for Part 1, and column-wise for Part 2.
+    type Input = (MathsSheet, MathsSheet);
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok((parse_input(input, true)?, parse_input(input, false)?))
+    }
+
+    fn part1((sheet, _): &Self::Input) -> Option<u64> {
+        Some(sheet.solve_all_lines())
+    }
+
+    fn part2((_, sheet): &Self::Input) -> Option<u64> {
+        Some(sheet.solve_all_lines())
+    }
+}
+
+/// Represents a maths sheet containing lines of numbers and a list of instructions.
+///
+/// # Fields
+///
+/// * `lines_of_numbers` - A vector of vectors, where each inner vector contains the numbers for a line.
+/// * `instructions` - The operation to apply to each line.
+#[derive(Debug)]
+pub struct MathsSheet {
+    lines_of_numbers: Vec<Vec<u64>>,
+    instructions: Vec<Operator>,
+}
+
+/// An operation from the bottom line of the maths sheet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operator {
+    /// `+`: add the numbers together.
+    Add,
+    /// `*`: multiply the numbers together.
+    Multiply,
+}
+
+/// Processes the input for Part 1 by splitting each line into numbers and collecting them column-wise.
+///
+/// # Arguments
+///
+/// * `input` - The lines of numbers separated by whitespace, with their line numbers.
+///
+/// # Returns
+///
+/// A vector of vectors of `u64`, where each inner vector contains the numbers from a column.
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] if a number is too big, or a line has a different number of
+/// columns to the first.
+fn process_input_part_1(input: &[(usize, &str)]) -> Result<Vec<Vec<u64>>, ParseError> {
+    let mut output_lines = Vec::new();
+    for &(line_no, line) in input {
+        let chars = line.split_whitespace();
+        if output_lines.is_empty() {
+            let char_count = chars.count();
+            output_lines = vec![Vec::new(); char_count]; // Instantiate vertical lines
+        } else if chars.count() != output_lines.len() {
+            return Err(ParseError::whole_line(
+                line_no,
+                line,
+                format!("expected {} numbers", output_lines.len()),
+            ));
+        }
+        for (pos, char) in line.split_whitespace().enumerate() {
+            let num = char
+                .parse::<u64>()
+                .map_err(|_| ParseError::at_substr(line_no, line, char, "number is too big"))?;
+            output_lines[pos].push(num);
+        }
+    }
+    Ok(output_lines)
+}
+
+/// Processes the input for Part 2, where each number is written top to bottom in its own
+/// column and problems are separated by blank columns.
+///
+/// # Arguments
+///
+/// * `input` - The lines of numbers, with their line numbers.
+///
+/// # Returns
+///
+/// A vector of vectors of `u64`, where each inner vector contains the numbers for a problem.
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] if a number is too big.
+fn process_input_part_2(input: &[(usize, &str)]) -> Result<Vec<Vec<u64>>, ParseError> {
+    let (first_line_no, first_line) = input.first().copied().unwrap_or((1, ""));
+    let mut fully_parsed = Vec::new();
+    for group in column_groups(input.iter().map(|(_, line)| *line)) {
+        let mut current_nums: Vec<u64> = Vec::new();
+        for (offset, column) in group.columns(ReadDirection::LeftToRight).iter().enumerate() {
+            let col = group.start_column + offset;
+            let num = column.replace(' ', "").parse::<u64>().map_err(|_| {
+                ParseError::new(first_line_no, first_line, col..col + 1, "number is too big")
+            })?;
+            current_nums.push(num);
+        }
+        fully_parsed.push(current_nums);
+    }
+
+    Ok(fully_parsed)
+}
+
+impl MathsSheet {
+    /// Solves one line by applying its operator to all of its numbers.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - The numbers of one line.
+    /// * `operator_pos` - The position of the line, and so of its operator.
+    ///
+    /// # Returns
+    ///
+    /// The sum or product of the numbers, depending on the operator.
+    fn solve_line(&self, line: &[u64], operator_pos: usize) -> u64 {
+        match self.instructions[operator_pos] {
+            Operator::Add => line.iter().sum(),
+            Operator::Multiply => line.iter().product(),
+        }
+    }
+
+    /// Solves all lines in the maths sheet by applying the corresponding operator to each line and summing the results.
+    ///
+    /// # Returns
+    ///
+    /// The grand total after processing all lines.
+    fn solve_all_lines(&self) -> u64 {
+        let mut grand_total: u64 = 0;
+        for pos in 0..self.lines_of_numbers.len() {
+            grand_total += self.solve_line(&self.lines_of_numbers[pos], pos);
+        }
+
+        grand_total
+    }
+}
+
+/// Parses the maths sheet: lines of numbers, followed by a line of operators ('+' or '*').
+///
+/// # Arguments
+///
+/// * `input` - The maths sheet.
+/// * `part_1` - Whether to read the numbers row-wise (Part 1, `true`) or column-wise
+///   (Part 2, `false`).
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] if there is anything other than digits and spaces above the
+/// operators, anything other than '+' and '*' among them, or not one operator per problem.
+fn parse_input(input: &str, part_1: bool) -> Result<MathsSheet, ParseError> {
+    let mut lines: Vec<(usize, &str)> = numbered_lines(input)
+        .filter(|(_, line)| !line.trim().is_empty())
+        .collect();
+    // instructions are on last line
+    let (instructions_line_no, instructions_line) = lines
+        .pop()
+        .ok_or_else(|| ParseError::whole_line(1, "", "expected a maths sheet"))?;
+    let mut instructions = Vec::new();
+    for instruction in instructions_line.split_ascii_whitespace() {
+        let operator = match instruction {
+            "+" => Operator::Add,
+            "*" => Operator::Multiply,
+            _ => {
+                return Err(ParseError::at_substr(
+                    instructions_line_no,
+                    instructions_line,
+                    instruction,
+                    "expected '+' or '*'",
+                ))
+            }
+        };
+        instructions.push(operator);
+    }
+
+    for (line_no, line) in &lines {
+        if let Some((col, c)) = line
+            .chars()
+            .enumerate()
+            .find(|(_, c)| !c.is_ascii_digit() && *c != ' ')
+        {
+            return Err(ParseError::new(
+                *line_no,
+                line,
+                col..col + 1,
+                format!("expected a digit, not {:?}", c),
+            ));
+        }
+    }
+    let lines_of_numbers = if part_1 {
+        process_input_part_1(&lines)?
+    } else {
+        process_input_part_2(&lines)?
+    };
+    if instructions.len() != lines_of_numbers.len() {
+        return Err(ParseError::whole_line(
+            instructions_line_no,
+            instructions_line,
+            format!(
+                "expected {} operators, one per problem",
+                lines_of_numbers.len()
+            ),
+        ));
+    }
+    Ok(MathsSheet {
+        lines_of_numbers,
+        instructions,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::input_source::InputSource;
+    #[test]
+    fn test_day_6() {
+        assert_eq!(
+            Day6::part1(&Day6::load(&InputSource::example(2025, 6))),
+            Some(4277556)
+        );
+    }
+    #[test]
+    fn test_day_6_part_2() {
+        assert_eq!(
+            Day6::part2(&Day6::load(&InputSource::example(2025, 6))),
+            Some(3263827)
+        );
+    }
+    #[test]
+    fn test_parse_errors() {
+        let err = parse_input("12 3\n4 56\n+ -", true).unwrap_err();
+        assert_eq!((err.line, err.columns), (3, 2..3));
+        let err = parse_input("12 3\n4 5x\n+ *", false).unwrap_err();
+        assert_eq!((err.line, err.columns), (2, 3..4));
+        let err = parse_input("12 3\n4\n+ *", true).unwrap_err();
+        assert_eq!((err.line, err.columns), (2, 0..1));
+        let err = parse_input("12 3\n\n4 99999999999999999999\n+ *", true).unwrap_err();
+        assert_eq!((err.line, err.columns), (3, 2..22));
+        let err = parse_input("12 3\n4 5\n+", true).unwrap_err();
+        assert_eq!((err.line, err.columns), (3, 0..1));
+        let err = parse_input("12 3\n4 5\n+ * +", false).unwrap_err();
+        assert_eq!((err.line, err.columns), (3, 0..5));
+    }
+}
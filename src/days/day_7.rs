@@ -1,24 +1,39 @@
 use std::collections::{HashMap, HashSet};
 
-use aoc_2025::utils::{get_input::get_aoc_input, parsing::split_lines};
-
-/// Runs the solution for Advent of Code Day 7.
-pub fn main() {
-    let part_1 = count_splits();
-    let manifold = parse_input();
-    let part_2 = recursive_find_paths(manifold, HashSet::new(), HashMap::new());
-    println!("Day 7! Part 1: {:?}, Part 2: {:?}", part_1, part_2);
+use crate::solution::Solution;
+use crate::utils::{grid::Grid, parsing::ParseError};
+
+/// The solution for Advent of Code Day 7.
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = TachyonManifold;
+    type Part1 = u32;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(manifold: &Self::Input) -> Option<u32> {
+        Some(count_splits(manifold.clone()))
+    }
+
+    fn part2(manifold: &Self::Input) -> Option<usize> {
+        Some(recursive_find_paths(manifold.clone(), HashSet::new(), HashMap::new()).len())
+    }
 }
 
+/// The state of the beams travelling down the manifold. Positions are (row, col), with
+/// beams moving down one row at a time.
 #[derive(Debug, Clone)]
-struct TachyonManifold {
+pub struct TachyonManifold {
     splitters: HashSet<(u32, u32)>,
     all_beam_coords: HashMap<u32, HashSet<u32>>,
     current_tachyon_beams_coords: HashSet<u32>,
     splits_so_far: u32,
-    max_x: u32,
-    max_y: u32,
-    current_x_cord: u32,
+    max_row: u32,
+    current_row: u32,
 }
 
 impl TachyonManifold {
@@ -28,24 +43,23 @@ impl TachyonManifold {
             all_beam_coords: HashMap::new(),
             current_tachyon_beams_coords: HashSet::new(),
             splits_so_far: 0,
-            max_x: 0,
-            max_y: 0,
-            current_x_cord: 0,
+            max_row: 0,
+            current_row: 0,
         }
     }
 
     fn advance_tachyons_by_1(&mut self, go_left: bool, go_right: bool) {
         let mut next_line_of_tachyons: HashSet<u32> = HashSet::new();
-        self.current_x_cord += 1;
+        self.current_row += 1;
         for tachyon in &self.current_tachyon_beams_coords {
-            if self.splitters.contains(&(self.current_x_cord, *tachyon)) {
-                let new_y_right = tachyon + 1;
-                let new_y_left = tachyon - 1;
+            if self.splitters.contains(&(self.current_row, *tachyon)) {
+                let new_col_right = tachyon + 1;
+                let new_col_left = tachyon - 1;
                 if go_left {
-                    next_line_of_tachyons.insert(new_y_left);
+                    next_line_of_tachyons.insert(new_col_left);
                 }
                 if go_right {
-                    next_line_of_tachyons.insert(new_y_right);
+                    next_line_of_tachyons.insert(new_col_right);
                 }
                 self.splits_so_far += 1
             } else {
@@ -53,12 +67,12 @@ impl TachyonManifold {
             }
         }
         self.all_beam_coords
-            .insert(self.current_x_cord, next_line_of_tachyons.clone());
+            .insert(self.current_row, next_line_of_tachyons.clone());
         self.current_tachyon_beams_coords = next_line_of_tachyons;
     }
 
     fn move_to_bottom_of_map(&mut self) {
-        while self.current_x_cord < self.max_x {
+        while self.current_row < self.max_row {
             self.advance_tachyons_by_1(true, true);
         }
     }
@@ -79,12 +93,12 @@ fn recursive_find_paths(
     mut coords_so_far: HashSet<Vec<u32>>,
     memo: HashMap<u32, HashSet<Vec<u32>>>,
 ) -> HashSet<Vec<u32>> {
-    let check_cache: u32 = manifold.clone().current_x_cord;
+    let check_cache: u32 = manifold.clone().current_row;
     let cached: Option<HashSet<Vec<u32>>> = memo.get(&check_cache).cloned();
     if cached.is_some() {
         return memo.get(&check_cache).unwrap().clone();
     }
-    if manifold.current_x_cord >= manifold.max_x {
+    if manifold.current_row >= manifold.max_row {
         coords_so_far.insert(manifold.clone().single_path_coords());
         return coords_so_far;
     }
@@ -101,28 +115,27 @@ fn recursive_find_paths(
     combined
 }
 
-fn parse_input() -> TachyonManifold {
-    let raw_input: Vec<String> = split_lines(get_aoc_input(2025, 7));
+/// Parses the manifold diagram, finding where the beam starts ('S') and where the
+/// splitters ('^') are.
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] if the diagram is not rectangular or has any other characters
+/// besides '.'.
+fn parse_input(input: &str) -> Result<TachyonManifold, ParseError> {
+    let grid = Grid::try_parse(input, |c| matches!(c, 'S' | '^' | '.').then_some(c))?;
     let mut manifold = TachyonManifold::new();
-    for (x_coord, line) in raw_input.iter().enumerate() {
-        for (y_coord, char) in line.chars().enumerate() {
-            if char.to_string() == "S" {
-                manifold.current_tachyon_beams_coords.insert(y_coord as u32);
-            } else if char.to_string() == "^" {
-                manifold.splitters.insert((x_coord as u32, y_coord as u32));
-            }
-            if y_coord as u32 > manifold.max_y {
-                manifold.max_y = y_coord as u32
-            }
-        }
-        if x_coord as u32 > manifold.max_x {
-            manifold.max_x = x_coord as u32
-        }
-    }
-    manifold
+    manifold.current_tachyon_beams_coords =
+        grid.find_all(&'S').map(|(_, col)| col as u32).collect();
+    manifold.splitters = grid
+        .find_all(&'^')
+        .map(|(row, col)| (row as u32, col as u32))
+        .collect();
+    manifold.max_row = grid.height().saturating_sub(1) as u32;
+    Ok(manifold)
 }
-fn count_splits() -> u32 {
-    let mut manifold = parse_input();
+
+fn count_splits(mut manifold: TachyonManifold) -> u32 {
     manifold.move_to_bottom_of_map();
 
     manifold.splits_so_far
@@ -131,14 +144,15 @@ fn count_splits() -> u32 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::utils::input_source::InputSource;
     #[test]
     fn test_day_7() {
-        assert_eq!(count_splits(), 21);
+        assert_eq!(count_splits(Day7::load(&InputSource::example(2025, 7))), 21);
     }
 
     #[test]
     fn test_part_2() {
-        let test_manifold = parse_input();
+        let test_manifold = Day7::load(&InputSource::example(2025, 7));
         let resp = recursive_find_paths(test_manifold, HashSet::new(), HashMap::new());
         assert_eq!(resp.len(), 40);
     }
@@ -13,10 +13,31 @@
 //     parsing::{split_lines, split_string_by_specified_char},
 // };
 
-// /// Runs the solution for Advent of Code Day 8.
-pub fn main() {
-    // let part_1 = parse_input();
-    // println!("Day 8! Part 1: {:?}", part_1);
+use crate::solution::Solution;
+use crate::utils::parsing::{ints_triple, numbered_lines, ParseError};
+
+/// The solution for Advent of Code Day 8. Only the input is parsed so far.
+pub struct Day8;
+
+impl Solution for Day8 {
+    /// The (x, y, z) position of each junction box.
+    type Input = Vec<(i64, i64, i64)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        numbered_lines(input)
+            .map(|(line_no, line)| ints_triple(line).map_err(|err| err.at_line(line_no, line)))
+            .collect()
+    }
+
+    fn part1(_junction_boxes: &Self::Input) -> Option<usize> {
+        None
+    }
+
+    fn part2(_junction_boxes: &Self::Input) -> Option<usize> {
+        None
+    }
 }
 
 // #[derive(Debug, Clone)]
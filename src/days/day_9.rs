@@ -0,0 +1,180 @@
+use std::{
+    cmp::{max, min},
+    collections::{HashMap, HashSet},
+};
+
+use crate::solution::Solution;
+use crate::utils::parsing::{ints_pair, numbered_lines, ParseError};
+
+/// The solution for Advent of Code Day 9. Part 2 is still a work in progress.
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = MovieTheatre;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(movie_theatre: &Self::Input) -> Option<u64> {
+        Some(largest_area(movie_theatre))
+    }
+
+    fn part2(_movie_theatre: &Self::Input) -> Option<u64> {
+        None
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MovieTheatre {
+    all_red_tile_coords: HashMap<u64, (u64, u64)>,
+    #[allow(dead_code)] // only used by part 2, which is still a work in progress
+    all_green_tile_coords: HashSet<(u64, u64)>,
+    rectangle_areas: HashMap<(u64, u64), u64>,
+    max_id: u64,
+}
+
+impl MovieTheatre {
+    fn new() -> Self {
+        MovieTheatre {
+            all_red_tile_coords: HashMap::new(),
+            all_green_tile_coords: HashSet::new(),
+            rectangle_areas: HashMap::new(),
+            max_id: 0,
+        }
+    }
+
+    fn add_coord(&mut self, coord: (u64, u64), id: u64) {
+        for (other_id, other_coord) in &self.all_red_tile_coords {
+            let sorted_key = (min(id, *other_id), max(id, *other_id));
+            self.rectangle_areas
+                .insert(sorted_key, rectangle_area(coord, *other_coord));
+        }
+        self.all_red_tile_coords.insert(id, coord);
+        if id > self.max_id {
+            self.max_id = id;
+        }
+    }
+
+    #[allow(dead_code)] // only used by part 2, which is still a work in progress
+    fn find_all_greens(&mut self) {
+        for (id, coord) in self.all_red_tile_coords.iter() {
+            let previous_red: &(u64, u64) = if *id == 0 {
+                self.all_red_tile_coords.get(&self.max_id).unwrap()
+            } else {
+                // There are other red tiles, time to calculate coords of green tiles!
+                self.all_red_tile_coords.get(&(id - 1)).unwrap()
+            };
+            let current_x = max(previous_red.0, coord.0);
+            let target_x = min(previous_red.0, coord.0);
+            let current_y = max(previous_red.1, coord.1);
+            let target_y = min(previous_red.1, coord.1);
+
+            let x_step: i64 = if current_x < target_x {
+                1
+            } else if current_x > target_x {
+                -1
+            } else {
+                0
+            };
+            let y_step: i64 = if current_y < target_y {
+                1
+            } else if current_y > target_y {
+                -1
+            } else {
+                0
+            };
+
+            let mut current = (current_x, current_y);
+            while current != (target_x, target_y) {
+                println!("{:?},{:?},{:?}", current, target_x, target_y);
+
+                self.all_green_tile_coords.insert(current);
+                current = (
+                    (current.0 as i64 + x_step) as u64,
+                    (current.1 as i64 + y_step) as u64,
+                );
+            }
+        }
+    }
+}
+
+fn rectangle_area(coord_1: (u64, u64), coord_2: (u64, u64)) -> u64 {
+    let y_diff = coord_2.1.abs_diff(coord_1.1) + 1;
+    let x_diff = coord_2.0.abs_diff(coord_1.0) + 1;
+
+    x_diff * y_diff
+}
+
+/// Parses the coordinates of the red tiles, one `x,y` pair per line.
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] if a line is not a pair of coordinates, or if there are fewer
+/// than two red tiles, since it takes two to make a rectangle.
+fn parse_input(input: &str) -> Result<MovieTheatre, ParseError> {
+    let mut movie_theatre = MovieTheatre::new();
+    let mut last_line = (1, "");
+    for (line_no, coords) in numbered_lines(input) {
+        let (x_coord, y_coord) =
+            ints_pair::<u64>(coords).map_err(|err| err.at_line(line_no, coords))?;
+        movie_theatre.add_coord((x_coord, y_coord), line_no as u64 - 1);
+        last_line = (line_no, coords);
+    }
+    if movie_theatre.rectangle_areas.is_empty() {
+        let (line_no, text) = last_line;
+        return Err(ParseError::whole_line(
+            line_no,
+            text,
+            "expected at least two red tiles",
+        ));
+    }
+
+    Ok(movie_theatre)
+}
+
+fn largest_area(movie_theatre: &MovieTheatre) -> u64 {
+    let largest_area = movie_theatre
+        .rectangle_areas
+        .values()
+        .max()
+        .expect("parse_input rejects fewer than two red tiles");
+    *largest_area
+}
+
+#[allow(dead_code)] // part 2 is still a work in progress
+fn largest_red_area_without_greens(mut movie_theatre: MovieTheatre) -> u64 {
+    movie_theatre.find_all_greens();
+    println!("{:?}", movie_theatre.all_green_tile_coords);
+
+    0 // Placeholder
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::input_source::InputSource;
+    #[test]
+    fn test_day_9() {
+        assert_eq!(
+            largest_area(&Day9::load(&InputSource::example(2025, 9))),
+            50
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = parse_input("").unwrap_err();
+        assert_eq!((err.line, err.columns), (1, 0..0));
+        let err = parse_input("7,1").unwrap_err();
+        assert_eq!((err.line, err.columns), (1, 0..3));
+        assert!(parse_input("7,1\n11,1").is_ok());
+    }
+
+    #[test]
+    fn test_day_9_part_2() {
+        largest_red_area_without_greens(Day9::load(&InputSource::example(2025, 9)));
+    }
+}
@@ -0,0 +1,30 @@
+use crate::solution::Day;
+
+pub mod day_1;
+pub mod day_10;
+pub mod day_11;
+pub mod day_12;
+pub mod day_2;
+pub mod day_3;
+pub mod day_4;
+pub mod day_5;
+pub mod day_6;
+pub mod day_7;
+pub mod day_8;
+pub mod day_9;
+
+/// Every day with a [`Solution`](crate::solution::Solution), in order.
+pub static DAYS: &[Day] = &[
+    Day::new::<day_1::Day1>(2025, 1),
+    Day::new::<day_2::Day2>(2025, 2),
+    Day::new::<day_3::Day3>(2025, 3),
+    Day::new::<day_4::Day4>(2025, 4),
+    Day::new::<day_5::Day5>(2025, 5),
+    Day::new::<day_6::Day6>(2025, 6),
+    Day::new::<day_7::Day7>(2025, 7),
+    Day::new::<day_8::Day8>(2025, 8),
+    Day::new::<day_9::Day9>(2025, 9),
+    Day::new::<day_10::Day10>(2025, 10),
+    Day::new::<day_11::Day11>(2025, 11),
+    Day::new::<day_12::Day12>(2025, 12),
+];
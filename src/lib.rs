@@ -1 +1,6 @@
+pub mod cli;
+pub mod days;
+pub mod output;
+pub mod solution;
+pub mod timing;
 pub mod utils;
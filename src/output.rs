@@ -0,0 +1,177 @@
+use serde::Serialize;
+
+use crate::solution::Answers;
+use crate::timing::Timings;
+use crate::utils::answers::Verdict;
+
+/// How the `aoc` binary prints its results.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Free-form text for people to read.
+    #[default]
+    Text,
+    /// One JSON object per part per line, for scripts to read (see [`PartResult`]).
+    Json,
+}
+
+/// What became of one part of a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    /// The part gave an answer.
+    Solved,
+    /// The part has not been solved yet, so gave no answer.
+    Unsolved,
+    /// The part gave the answer the website accepted.
+    Correct,
+    /// The part did not give the answer the website accepted.
+    Wrong,
+    /// The input could not be loaded or parsed, so the part was not run.
+    Error,
+}
+
+/// How long solving a part took, in nanoseconds. Loading and parsing the input are shared
+/// by both parts of a day, so both report the same `load_ns` and `parse_ns`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct PartTimings {
+    pub load_ns: u128,
+    pub parse_ns: u128,
+    pub part_ns: u128,
+}
+
+/// The result of one part of a day, as printed by [`OutputFormat::Json`].
+///
+/// # Example
+/// ```rust
+/// use aoc_2025::output::PartResult;
+/// use aoc_2025::solution::Answers;
+/// use aoc_2025::timing::Timings;
+/// let answers = Answers { part1: Some("3".to_string()), part2: None };
+/// let result = PartResult::new(2025, 5, 2, &answers, &Timings::default());
+/// assert_eq!(
+///     result.to_json_line(),
+///     concat!(
+///         r#"{"year":2025,"day":5,"part":2,"answer":null,"status":"unsolved","#,
+///         r#""timings":{"load_ns":0,"parse_ns":0,"part_ns":0}}"#
+///     )
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PartResult {
+    pub year: u32,
+    pub day: u32,
+    pub part: u8,
+    /// The answer, or `None` if there is none.
+    pub answer: Option<String>,
+    pub status: Status,
+    /// The accepted answer, if the part was checked against one and got it wrong.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected: Option<String>,
+    /// Why the part could not be run, if it could not.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// How long the part took, if it was run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timings: Option<PartTimings>,
+}
+
+impl PartResult {
+    /// The result of a part which was run, with `timings` for the whole day.
+    pub fn new(year: u32, day: u32, part: u8, answers: &Answers, timings: &Timings) -> Self {
+        let answer = answers.part(part).map(str::to_string);
+        let part_time = if part == 1 {
+            timings.part1
+        } else {
+            timings.part2
+        };
+        PartResult {
+            year,
+            day,
+            part,
+            status: match answer {
+                Some(_) => Status::Solved,
+                None => Status::Unsolved,
+            },
+            answer,
+            expected: None,
+            error: None,
+            timings: Some(PartTimings {
+                load_ns: timings.load.as_nanos(),
+                parse_ns: timings.parse.as_nanos(),
+                part_ns: part_time.as_nanos(),
+            }),
+        }
+    }
+
+    /// The result of a part which could not be run because of `error`.
+    pub fn failed(year: u32, day: u32, part: u8, error: &str) -> Self {
+        PartResult {
+            year,
+            day,
+            part,
+            answer: None,
+            status: Status::Error,
+            expected: None,
+            error: Some(error.to_string()),
+            timings: None,
+        }
+    }
+
+    /// Marks the result as correct or wrong, going by how it compared with the accepted
+    /// answer. An [`Verdict::Unknown`] leaves it as it was.
+    pub fn checked(mut self, verdict: &Verdict) -> Self {
+        match verdict {
+            Verdict::Correct => self.status = Status::Correct,
+            Verdict::Wrong { expected } => {
+                self.status = Status::Wrong;
+                self.expected = Some(expected.clone());
+            }
+            Verdict::Unknown => {}
+        }
+        self
+    }
+
+    /// The result as a single line of JSON, without a line ending.
+    pub fn to_json_line(&self) -> String {
+        serde_json::to_string(self).expect("A PartResult is always valid JSON")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_json_lines() {
+        let answers = Answers {
+            part1: Some("3".to_string()),
+            part2: Some("15".to_string()),
+        };
+        let timings = Timings {
+            load: Duration::from_nanos(40),
+            parse: Duration::from_nanos(100),
+            part1: Duration::from_nanos(7),
+            part2: Duration::from_nanos(9),
+        };
+        let wrong = Verdict::Wrong {
+            expected: "14".to_string(),
+        };
+        assert_eq!(
+            PartResult::new(2025, 5, 1, &answers, &timings)
+                .checked(&Verdict::Correct)
+                .to_json_line(),
+            r#"{"year":2025,"day":5,"part":1,"answer":"3","status":"correct","timings":{"load_ns":40,"parse_ns":100,"part_ns":7}}"#
+        );
+        assert_eq!(
+            PartResult::new(2025, 5, 2, &answers, &timings)
+                .checked(&wrong)
+                .to_json_line(),
+            r#"{"year":2025,"day":5,"part":2,"answer":"15","status":"wrong","expected":"14","timings":{"load_ns":40,"parse_ns":100,"part_ns":9}}"#
+        );
+        assert_eq!(
+            PartResult::failed(2025, 5, 1, "line 3: expected a number").to_json_line(),
+            r#"{"year":2025,"day":5,"part":1,"answer":null,"status":"error","error":"line 3: expected a number"}"#
+        );
+    }
+}
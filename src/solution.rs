@@ -0,0 +1,237 @@
+use std::fmt;
+use std::time::Instant;
+
+use crate::days::DAYS;
+use crate::timing::Timings;
+use crate::utils::{input_source::InputSource, parsing::ParseError};
+
+/// A solution to one day's puzzle: the input is parsed once, then each part is solved
+/// from the parsed input.
+pub trait Solution {
+    /// The puzzle input, parsed.
+    type Input;
+    /// The answer to part 1.
+    type Part1: fmt::Display;
+    /// The answer to part 2.
+    type Part2: fmt::Display;
+
+    /// Parses the puzzle input.
+    ///
+    /// # Errors
+    /// Returns a [`ParseError`] pointing at the problem if the input is malformed.
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    /// Solves part 1, or returns `None` if it has not been solved yet.
+    fn part1(input: &Self::Input) -> Option<Self::Part1>;
+
+    /// Solves part 2, or returns `None` if it has not been solved yet.
+    fn part2(input: &Self::Input) -> Option<Self::Part2>;
+
+    /// Loads and parses the puzzle input.
+    ///
+    /// # Panics
+    /// Panics if the input cannot be loaded or parsed.
+    fn load(source: &InputSource) -> Self::Input {
+        let input = source.load().expect("Failed to get puzzle input");
+        Self::parse(&input).unwrap_or_else(|err| panic!("{}", err))
+    }
+}
+
+/// The answers to both parts of a puzzle, as they would be submitted. `None` for a part
+/// which has not been solved yet.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Answers {
+    /// The answer to the given part (`1` or `2`), if it has been solved. There is never an
+    /// answer to any other part.
+    pub fn part(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let show = |answer: &Option<String>| {
+            answer
+                .clone()
+                .unwrap_or_else(|| "Not done yet!".to_string())
+        };
+        write!(
+            f,
+            "Part 1: {}, Part 2: {}",
+            show(&self.part1),
+            show(&self.part2)
+        )
+    }
+}
+
+/// Parses an input and solves the given part, or both parts if there is none.
+type SolveFn = fn(&str, Option<u8>) -> Result<(Answers, Timings), ParseError>;
+
+/// A day's [`Solution`], which can be run without knowing its types. The days are listed in
+/// [`DAYS`]; use [`find`] to look one up.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub year: u32,
+    pub day: u32,
+    solve: SolveFn,
+    parse: fn(&str) -> Result<(), ParseError>,
+}
+
+impl Day {
+    /// The solution `S` to the puzzle for the given year and day.
+    pub const fn new<S: Solution>(year: u32, day: u32) -> Self {
+        Day {
+            year,
+            day,
+            solve: solve::<S>,
+            parse: parse::<S>,
+        }
+    }
+
+    /// Parses `input` without solving either part, to check that it can be parsed.
+    ///
+    /// # Errors
+    /// Returns a [`ParseError`] if the input is malformed.
+    pub fn parse(&self, input: &str) -> Result<(), ParseError> {
+        (self.parse)(input)
+    }
+
+    /// Parses `input` and solves both parts.
+    ///
+    /// # Errors
+    /// Returns a [`ParseError`] if the input is malformed.
+    pub fn solve(&self, input: &str) -> Result<Answers, ParseError> {
+        self.solve_timed(input).map(|(answers, _)| answers)
+    }
+
+    /// Like [`Day::solve`], but also times parsing and each part. Loading the input is up
+    /// to the caller, so [`Timings::load`] is left at zero.
+    ///
+    /// # Errors
+    /// Returns a [`ParseError`] if the input is malformed.
+    pub fn solve_timed(&self, input: &str) -> Result<(Answers, Timings), ParseError> {
+        (self.solve)(input, None)
+    }
+
+    /// Parses `input` and solves just the given part (`1` or `2`), returning `None` if it
+    /// has not been solved yet.
+    ///
+    /// # Errors
+    /// Returns a [`ParseError`] if the input is malformed.
+    pub fn solve_part(&self, input: &str, part: u8) -> Result<Option<String>, ParseError> {
+        self.solve_part_timed(input, part)
+            .map(|(answers, _)| answers.part(part).map(str::to_string))
+    }
+
+    /// Like [`Day::solve_timed`], but only solves the given part. The other part's answer is
+    /// left as `None` and its time at zero.
+    ///
+    /// # Errors
+    /// Returns a [`ParseError`] if the input is malformed.
+    pub fn solve_part_timed(
+        &self,
+        input: &str,
+        part: u8,
+    ) -> Result<(Answers, Timings), ParseError> {
+        (self.solve)(input, Some(part))
+    }
+}
+
+impl fmt::Debug for Day {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Day")
+            .field("year", &self.year)
+            .field("day", &self.day)
+            .finish_non_exhaustive()
+    }
+}
+
+fn parse<S: Solution>(input: &str) -> Result<(), ParseError> {
+    S::parse(input).map(|_| ())
+}
+
+/// Parses `input` and solves `part`, or both parts if it is `None`.
+fn solve<S: Solution>(input: &str, part: Option<u8>) -> Result<(Answers, Timings), ParseError> {
+    let mut timings = Timings::default();
+    let mut answers = Answers::default();
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    timings.parse = start.elapsed();
+
+    if matches!(part, None | Some(1)) {
+        let start = Instant::now();
+        answers.part1 = S::part1(&parsed).map(|answer| answer.to_string());
+        timings.part1 = start.elapsed();
+    }
+
+    if matches!(part, None | Some(2)) {
+        let start = Instant::now();
+        answers.part2 = S::part2(&parsed).map(|answer| answer.to_string());
+        timings.part2 = start.elapsed();
+    }
+
+    Ok((answers, timings))
+}
+
+/// Looks up the solution to the puzzle for the given year and day.
+///
+/// # Example
+/// ```rust
+/// use aoc_2025::solution::find;
+/// assert_eq!(find(2025, 1).map(|day| day.day), Some(1));
+/// assert!(find(2025, 13).is_none());
+/// ```
+pub fn find(year: u32, day: u32) -> Option<&'static Day> {
+    DAYS.iter()
+        .find(|solution| solution.year == year && solution.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_every_day_solves_its_example() {
+        for solution in DAYS {
+            let source = InputSource::example(solution.year, solution.day);
+            let input = source.load().unwrap();
+            assert!(solution.parse(&input).is_ok(), "{:?}", solution);
+            assert!(solution.solve(&input).is_ok(), "{:?}", solution);
+        }
+    }
+
+    #[test]
+    fn test_answers_part() {
+        let answers = Answers {
+            part1: Some("1".to_string()),
+            part2: Some("2".to_string()),
+        };
+        assert_eq!(answers.part(1), Some("1"));
+        assert_eq!(answers.part(2), Some("2"));
+        assert_eq!(answers.part(0), None);
+        assert_eq!(answers.part(7), None);
+    }
+
+    #[test]
+    fn test_solve_part_only_solves_that_part() {
+        let day = find(2025, 1).unwrap();
+        let input = InputSource::example(2025, 1).load().unwrap();
+        let both = day.solve(&input).unwrap();
+        assert_eq!(day.solve_part(&input, 1).unwrap().as_deref(), both.part(1));
+        assert_eq!(day.solve_part(&input, 2).unwrap().as_deref(), both.part(2));
+
+        let (answers, timings) = day.solve_part_timed(&input, 1).unwrap();
+        assert_eq!(answers.part2, None);
+        assert_eq!(timings.part2, Duration::ZERO);
+    }
+}
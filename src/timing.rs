@@ -0,0 +1,279 @@
+use std::fmt::Write;
+use std::ops::Add;
+use std::time::Duration;
+
+/// How long each phase of solving a puzzle took.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Timings {
+    /// Reading the input from its [`InputSource`](crate::utils::input_source::InputSource).
+    pub load: Duration,
+    /// Parsing the input.
+    pub parse: Duration,
+    /// Solving part 1 from the parsed input.
+    pub part1: Duration,
+    /// Solving part 2 from the parsed input.
+    pub part2: Duration,
+}
+
+impl Timings {
+    /// The time taken by every phase together.
+    pub fn total(&self) -> Duration {
+        self.load + self.parse + self.part1 + self.part2
+    }
+
+    /// The phases in order, for working on each one in turn.
+    fn phases(&self) -> [Duration; 4] {
+        [self.load, self.parse, self.part1, self.part2]
+    }
+
+    fn from_phases(phases: [Duration; 4]) -> Self {
+        let [load, parse, part1, part2] = phases;
+        Timings {
+            load,
+            parse,
+            part1,
+            part2,
+        }
+    }
+}
+
+impl Add for Timings {
+    type Output = Timings;
+
+    fn add(self, other: Timings) -> Timings {
+        let (mine, theirs) = (self.phases(), other.phases());
+        Timings::from_phases([0, 1, 2, 3].map(|i| mine[i] + theirs[i]))
+    }
+}
+
+/// The timings for one day, summarised over however many times it was run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimingRow {
+    pub year: u32,
+    pub day: u32,
+    /// How many times the day was run.
+    pub runs: usize,
+    /// The fastest time for each phase, taken separately.
+    pub min: Timings,
+    /// The median time for each phase, taken separately. For an even number of runs this
+    /// is the faster of the middle two.
+    pub median: Timings,
+    /// The total time of the fastest single run.
+    pub best: Duration,
+}
+
+impl TimingRow {
+    /// Summarises the timings from running a day one or more times.
+    ///
+    /// # Panics
+    /// Panics if `samples` is empty.
+    pub fn new(year: u32, day: u32, samples: &[Timings]) -> Self {
+        assert!(!samples.is_empty(), "Need at least one run to summarise");
+        let sorted = |phase: usize| {
+            let mut times: Vec<Duration> = samples.iter().map(|t| t.phases()[phase]).collect();
+            times.sort();
+            times
+        };
+        let phases = [0, 1, 2, 3].map(sorted);
+        TimingRow {
+            year,
+            day,
+            runs: samples.len(),
+            min: Timings::from_phases(phases.clone().map(|times| times[0])),
+            median: Timings::from_phases(phases.map(|times| times[(times.len() - 1) / 2])),
+            best: samples.iter().map(Timings::total).min().unwrap(),
+        }
+    }
+}
+
+/// A table of how long each day takes, phase by phase, with a total across all days.
+///
+/// Days whose median total is over the budget are flagged.
+///
+/// # Example
+/// ```rust
+/// use std::time::Duration;
+/// use aoc_2025::timing::{TimingReport, TimingRow, Timings};
+/// let fast = Timings { parse: Duration::from_millis(2), ..Timings::default() };
+/// let slow = Timings { part2: Duration::from_secs(3), ..Timings::default() };
+/// let mut report = TimingReport::new(Some(Duration::from_secs(1)));
+/// report.push(TimingRow::new(2025, 1, &[fast]));
+/// report.push(TimingRow::new(2025, 2, &[slow]));
+/// assert_eq!(report.total(), fast + slow);
+/// assert_eq!(report.over_budget().map(|row| row.day).collect::<Vec<_>>(), [2]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TimingReport {
+    rows: Vec<TimingRow>,
+    budget: Option<Duration>,
+}
+
+impl TimingReport {
+    /// An empty report, flagging days which take longer than `budget` if there is one.
+    pub fn new(budget: Option<Duration>) -> Self {
+        TimingReport {
+            rows: Vec::new(),
+            budget,
+        }
+    }
+
+    /// Adds a day to the report.
+    pub fn push(&mut self, row: TimingRow) {
+        self.rows.push(row);
+    }
+
+    /// The days in the report, in the order they were added.
+    pub fn rows(&self) -> &[TimingRow] {
+        &self.rows
+    }
+
+    /// The median timings of every day added together.
+    pub fn total(&self) -> Timings {
+        self.rows
+            .iter()
+            .fold(Timings::default(), |total, row| total + row.median)
+    }
+
+    /// The days whose median total is over the budget.
+    pub fn over_budget(&self) -> impl Iterator<Item = &TimingRow> {
+        self.rows.iter().filter(move |row| match self.budget {
+            Some(budget) => row.median.total() > budget,
+            None => false,
+        })
+    }
+
+    /// Renders the report as a plain-text table of median times, with the total of the
+    /// fastest run as well if any day was run more than once. Days over budget are marked with `!`.
+    pub fn render(&self) -> String {
+        let repeated = self.rows.iter().any(|row| row.runs > 1);
+        let mut out = String::new();
+        let _ = write!(
+            out,
+            "{:>4} {:>9} {:>9} {:>9} {:>9} {:>9}",
+            "Day", "Load", "Parse", "Part 1", "Part 2", "Total"
+        );
+        if repeated {
+            let _ = write!(out, " {:>9}", "Best");
+        }
+        out.push('\n');
+
+        let over: Vec<u32> = self.over_budget().map(|row| row.day).collect();
+        for row in &self.rows {
+            let flag = if over.contains(&row.day) { '!' } else { ' ' };
+            let _ = write!(out, "{:>3}{} ", row.day, flag);
+            write_timings(&mut out, &row.median);
+            if repeated {
+                let _ = write!(out, " {:>9}", format_duration(row.best));
+            }
+            out.push('\n');
+        }
+
+        out.push_str("Total");
+        write_timings(&mut out, &self.total());
+        out.push('\n');
+
+        if let Some(budget) = self.budget {
+            let _ = writeln!(
+                out,
+                "{} of {} days over the {} budget",
+                over.len(),
+                self.rows.len(),
+                format_duration(budget)
+            );
+        }
+        out
+    }
+}
+
+fn write_timings(out: &mut String, timings: &Timings) {
+    for phase in timings.phases() {
+        let _ = write!(out, "{:>9} ", format_duration(phase));
+    }
+    let _ = write!(out, "{:>9}", format_duration(timings.total()));
+}
+
+/// Formats a duration to three significant figures or so, in whichever of seconds,
+/// milliseconds or microseconds suits it best.
+///
+/// # Example
+/// ```rust
+/// use std::time::Duration;
+/// use aoc_2025::timing::format_duration;
+/// assert_eq!(format_duration(Duration::from_micros(1500)), "1.50ms");
+/// assert_eq!(format_duration(Duration::from_secs(12)), "12.0s");
+/// ```
+pub fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs_f64();
+    let (value, unit) = if secs >= 1.0 {
+        (secs, "s")
+    } else if secs >= 1e-3 {
+        (secs * 1e3, "ms")
+    } else {
+        (secs * 1e6, "µs")
+    };
+    let decimals = match value {
+        _ if value >= 100.0 => 0,
+        _ if value >= 10.0 => 1,
+        _ => 2,
+    };
+    format!("{:.*}{}", decimals, value, unit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(load: u64, parse: u64, part1: u64, part2: u64) -> Timings {
+        Timings {
+            load: Duration::from_millis(load),
+            parse: Duration::from_millis(parse),
+            part1: Duration::from_millis(part1),
+            part2: Duration::from_millis(part2),
+        }
+    }
+
+    #[test]
+    fn test_min_and_median_are_per_phase() {
+        let samples = [millis(3, 1, 9, 4), millis(1, 2, 8, 6), millis(2, 3, 7, 5)];
+        let row = TimingRow::new(2025, 7, &samples);
+        assert_eq!(row.runs, 3);
+        assert_eq!(row.min, millis(1, 1, 7, 4));
+        assert_eq!(row.median, millis(2, 2, 8, 5));
+        assert_eq!(row.best, Duration::from_millis(17));
+
+        let row = TimingRow::new(2025, 7, &samples[..2]);
+        assert_eq!(row.median, millis(1, 1, 8, 4));
+    }
+
+    #[test]
+    fn test_render() {
+        let mut report = TimingReport::new(Some(Duration::from_millis(100)));
+        report.push(TimingRow::new(2025, 1, &[millis(1, 2, 3, 4)]));
+        report.push(TimingRow::new(2025, 2, &[millis(0, 1, 50, 150)]));
+        let rendered = report.render();
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert_eq!(
+            lines,
+            [
+                " Day      Load     Parse    Part 1    Part 2     Total",
+                "  1     1.00ms    2.00ms    3.00ms    4.00ms    10.0ms",
+                "  2!    0.00µs    1.00ms    50.0ms     150ms     201ms",
+                "Total   1.00ms    3.00ms    53.0ms     154ms     211ms",
+                "1 of 2 days over the 100ms budget",
+            ]
+        );
+    }
+    #[test]
+    fn test_render_best_is_fastest_run() {
+        let mut report = TimingReport::new(None);
+        report.push(TimingRow::new(
+            2025,
+            1,
+            &[millis(0, 1, 9, 0), millis(0, 9, 1, 0)],
+        ));
+        let rendered = report.render();
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert_eq!(lines[0].split_whitespace().last(), Some("Best"));
+        assert_eq!(lines[1].split_whitespace().last(), Some("10.0ms"));
+    }
+}
@@ -0,0 +1,262 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::utils::error::AocInputError;
+use crate::utils::session::{profile_from_env, validate_profile};
+
+/// Default directory (relative to the working directory) where accepted answers are kept.
+pub const DEFAULT_ANSWERS_DIR: &str = "answers";
+
+/// The answers accepted by the website for one year, keyed by day and then by part.
+///
+/// # Example
+/// ```rust
+/// use aoc_2025::utils::answers::{KnownAnswers, Verdict};
+/// let mut known = KnownAnswers::default();
+/// known.insert(1, 1, "1023");
+/// assert_eq!(known.check(1, 1, Some("1023")), Verdict::Correct);
+/// assert_eq!(
+///     known.check(1, 1, Some("1024")),
+///     Verdict::Wrong { expected: "1023".to_string() }
+/// );
+/// assert_eq!(known.check(1, 2, Some("5899")), Verdict::Unknown);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct KnownAnswers(BTreeMap<u32, BTreeMap<u8, String>>);
+
+impl KnownAnswers {
+    /// The accepted answer for one part of a day, if there is one.
+    pub fn get(&self, day: u32, part: u8) -> Option<&str> {
+        self.0
+            .get(&day)
+            .and_then(|parts| parts.get(&part))
+            .map(String::as_str)
+    }
+
+    /// Records the accepted answer for one part of a day, replacing any previous one.
+    pub fn insert(&mut self, day: u32, part: u8, answer: &str) {
+        self.0
+            .entry(day)
+            .or_default()
+            .insert(part, answer.to_string());
+    }
+
+    /// The days with at least one accepted answer, in order.
+    pub fn days(&self) -> impl Iterator<Item = u32> + '_ {
+        self.0.keys().copied()
+    }
+
+    /// Compares an answer with the accepted one. A part which has no answer (`None`) is
+    /// wrong if there is an accepted answer for it.
+    pub fn check(&self, day: u32, part: u8, answer: Option<&str>) -> Verdict {
+        match self.get(day, part) {
+            None => Verdict::Unknown,
+            Some(expected) if answer == Some(expected) => Verdict::Correct,
+            Some(expected) => Verdict::Wrong {
+                expected: expected.to_string(),
+            },
+        }
+    }
+}
+
+/// How an answer compares with the accepted one, as worked out by [`KnownAnswers::check`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    /// The answer is the one which was accepted.
+    Correct,
+    /// The answer is not the one which was accepted, which was `expected`.
+    Wrong { expected: String },
+    /// No answer has been accepted for this part yet.
+    Unknown,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::Wrong { expected } => write!(f, "wrong, expected {}", expected),
+            Verdict::Unknown => write!(f, "no accepted answer yet"),
+        }
+    }
+}
+
+/// A file of the answers the website has accepted, one per year and profile, so that solved
+/// days can be checked for regressions.
+///
+/// The answers are stored as `<root>/<year>.json`, or `<root>/<year>.<profile>.json` for a
+/// named profile (see [`SessionResolver`](crate::utils::session::SessionResolver)), since
+/// each account has its own inputs and so its own answers. They are kept apart from the
+/// [`InputCache`](crate::utils::cache::InputCache), so purging cached inputs never loses
+/// them.
+///
+/// # Example
+/// ```rust
+/// use aoc_2025::utils::answers::AnswerStore;
+/// let store = AnswerStore::new("answers").profile("alice");
+/// assert!(store.path_for(2025).ends_with("answers/2025.alice.json"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct AnswerStore {
+    root: PathBuf,
+    profile: Option<String>,
+}
+
+impl Default for AnswerStore {
+    /// A store in the default directory, for the profile named by `AOC_PROFILE` if that is
+    /// set.
+    fn default() -> Self {
+        AnswerStore {
+            root: PathBuf::from(DEFAULT_ANSWERS_DIR),
+            profile: profile_from_env(),
+        }
+    }
+}
+
+impl AnswerStore {
+    /// A store rooted at the given directory, for the default profile.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        AnswerStore {
+            root: root.into(),
+            profile: None,
+        }
+    }
+
+    /// Uses the answers for the given named profile instead of the default one.
+    pub fn profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+
+    /// The path the answers for the given year are (or would be) stored at.
+    pub fn path_for(&self, year: u32) -> PathBuf {
+        let file = match &self.profile {
+            Some(profile) => format!("{}.{}.json", year, profile),
+            None => format!("{}.json", year),
+        };
+        self.root.join(file)
+    }
+
+    /// Reads the accepted answers for a year. A year with no answers file has no answers.
+    ///
+    /// # Errors
+    /// Returns [`AocInputError::InvalidSession`] if the profile name is unusable, or
+    /// [`AocInputError::Io`] if the file cannot be read or is not valid.
+    pub fn load(&self, year: u32) -> Result<KnownAnswers, AocInputError> {
+        let path = self.checked_path_for(year)?;
+        match fs::read_to_string(&path) {
+            Ok(json) => serde_json::from_str(&json).map_err(|err| invalid_data(&path, err)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(KnownAnswers::default()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Writes the accepted answers for a year, replacing the file.
+    ///
+    /// The file is written to a temporary file first and then renamed into place, so an
+    /// interrupted run never loses the answers recorded so far.
+    ///
+    /// # Errors
+    /// As for [`AnswerStore::load`].
+    pub fn save(&self, year: u32, answers: &KnownAnswers) -> Result<(), AocInputError> {
+        let path = self.checked_path_for(year)?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let json = serde_json::to_string_pretty(answers).map_err(|err| invalid_data(&path, err))?;
+        let tmp_path = path.with_extension("json.tmp");
+        fs::write(&tmp_path, json + "\n")?;
+        fs::rename(tmp_path, &path)?;
+        Ok(())
+    }
+
+    /// Records one accepted answer, keeping the others already in the file.
+    ///
+    /// # Errors
+    /// As for [`AnswerStore::load`].
+    pub fn record(&self, year: u32, day: u32, part: u8, answer: &str) -> Result<(), AocInputError> {
+        let mut answers = self.load(year)?;
+        answers.insert(day, part, answer);
+        self.save(year, &answers)
+    }
+
+    fn checked_path_for(&self, year: u32) -> Result<PathBuf, AocInputError> {
+        if let Some(profile) = &self.profile {
+            validate_profile(profile)?;
+        }
+        Ok(self.path_for(year))
+    }
+}
+
+fn invalid_data(path: &Path, err: serde_json::Error) -> AocInputError {
+    AocInputError::Io(io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("{} is not a valid answers file: {}", path.display(), err),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::cache::InputCache;
+
+    fn temp_store(name: &str) -> AnswerStore {
+        let root =
+            std::env::temp_dir().join(format!("aoc-answers-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        AnswerStore::new(root)
+    }
+
+    #[test]
+    fn test_record_round_trip() {
+        let store = temp_store("round-trip");
+        assert_eq!(store.load(2025).unwrap(), KnownAnswers::default());
+        store.record(2025, 5, 1, "3").unwrap();
+        store.record(2025, 5, 2, "14").unwrap();
+        store.record(2025, 1, 1, "1023").unwrap();
+
+        let known = store.load(2025).unwrap();
+        assert_eq!(known.get(5, 2), Some("14"));
+        assert_eq!(known.days().collect::<Vec<_>>(), [1, 5]);
+        assert_eq!(known.check(1, 2, None), Verdict::Unknown);
+        assert_eq!(
+            known.check(5, 1, None),
+            Verdict::Wrong {
+                expected: "3".to_string()
+            }
+        );
+
+        let alice = store.clone().profile("alice");
+        assert_eq!(alice.load(2025).unwrap(), KnownAnswers::default());
+        assert!(store.clone().profile("../alice").load(2025).is_err());
+        fs::remove_dir_all(&store.root).unwrap();
+    }
+
+    #[test]
+    fn test_purging_inputs_keeps_answers() {
+        let store = temp_store("purge");
+        let cache = InputCache::new(store.root.join("inputs"));
+        let store = AnswerStore::new(store.root.join("answers"));
+        cache.write(2025, 5, "3-5\n\n1\n").unwrap();
+        store.record(2025, 5, 1, "3").unwrap();
+
+        assert!(cache.purge_year(2025).unwrap());
+        assert_eq!(store.load(2025).unwrap().get(5, 1), Some("3"));
+        fs::remove_dir_all(store.root.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_load_rejects_bad_file() {
+        let store = temp_store("bad-file");
+        let path = store.path_for(2025);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "{\"1\": [\"1023\"]}").unwrap();
+        assert!(matches!(store.load(2025), Err(AocInputError::Io(_))));
+        fs::remove_dir_all(&store.root).unwrap();
+    }
+}
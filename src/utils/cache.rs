@@ -0,0 +1,182 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::utils::session::{profile_from_env, validate_profile};
+
+/// Default directory (relative to the working directory) where puzzle inputs are cached.
+pub const DEFAULT_CACHE_DIR: &str = "inputs";
+
+/// A persistent on-disk cache of puzzle inputs.
+///
+/// Inputs are stored one file per day, laid out as `<root>/<year>/day_<day>.txt`, so that
+/// each input only ever has to be downloaded once. Each account has its own inputs, so for a
+/// named profile (see [`SessionResolver`](crate::utils::session::SessionResolver)) they are
+/// stored as `<root>/<year>/day_<day>.<profile>.txt` instead.
+///
+/// # Example
+/// ```rust
+/// use aoc_2025::utils::cache::InputCache;
+/// let cache = InputCache::default();
+/// assert!(cache.path_for(2025, 1).ends_with("2025/day_1.txt"));
+/// let cache = InputCache::new("inputs").profile("alice");
+/// assert!(cache.path_for(2025, 1).ends_with("2025/day_1.alice.txt"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct InputCache {
+    root: PathBuf,
+    profile: Option<String>,
+}
+
+impl Default for InputCache {
+    /// A cache in the default directory, for the profile named by `AOC_PROFILE` if that is
+    /// set.
+    fn default() -> Self {
+        InputCache {
+            root: PathBuf::from(DEFAULT_CACHE_DIR),
+            profile: profile_from_env(),
+        }
+    }
+}
+
+impl InputCache {
+    /// Creates a cache rooted at the given directory. The directory is created lazily,
+    /// the first time an input is written to it.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        InputCache {
+            root: root.into(),
+            profile: None,
+        }
+    }
+
+    /// Uses the inputs for the given named profile instead of the default one.
+    pub fn profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+
+    /// The directory this cache lives in.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// The path the input for the given year and day is (or would be) cached at.
+    pub fn path_for(&self, year: u32, day: u32) -> PathBuf {
+        let file = match &self.profile {
+            Some(profile) => format!("day_{}.{}.txt", day, profile),
+            None => format!("day_{}.txt", day),
+        };
+        self.year_dir(year).join(file)
+    }
+
+    /// Returns the cached input for the given year and day, or `None` if it has not been
+    /// cached yet (or cannot be read).
+    pub fn read(&self, year: u32, day: u32) -> Option<String> {
+        fs::read_to_string(self.checked_path_for(year, day).ok()?).ok()
+    }
+
+    /// Stores an input in the cache, creating the year directory if needed.
+    ///
+    /// The input is written to a temporary file first and then renamed into place, so an
+    /// interrupted run never leaves a truncated input behind.
+    pub fn write(&self, year: u32, day: u32, contents: &str) -> io::Result<()> {
+        let path = self.checked_path_for(year, day)?;
+        fs::create_dir_all(self.year_dir(year))?;
+        let tmp_path = path.with_extension("txt.tmp");
+        fs::write(&tmp_path, contents)?;
+        fs::rename(tmp_path, path)
+    }
+
+    /// Removes the cached input for a single day.
+    ///
+    /// # Returns
+    /// `true` if a cached input was removed, `false` if there was nothing to remove.
+    pub fn purge_day(&self, year: u32, day: u32) -> io::Result<bool> {
+        ignore_not_found(fs::remove_file(self.checked_path_for(year, day)?))
+    }
+
+    /// Removes every cached input for a year.
+    ///
+    /// # Returns
+    /// `true` if the year had a cache directory which was removed, `false` otherwise.
+    pub fn purge_year(&self, year: u32) -> io::Result<bool> {
+        ignore_not_found(fs::remove_dir_all(self.year_dir(year)))
+    }
+
+    /// Like [`InputCache::path_for`], but fails if the profile name is not safe to use in
+    /// a file name.
+    fn checked_path_for(&self, year: u32, day: u32) -> io::Result<PathBuf> {
+        if let Some(profile) = &self.profile {
+            validate_profile(profile)
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err.to_string()))?;
+        }
+        Ok(self.path_for(year, day))
+    }
+
+    fn year_dir(&self, year: u32) -> PathBuf {
+        self.root.join(year.to_string())
+    }
+}
+
+/// Converts the result of a removal into whether anything was removed, treating a path that
+/// does not exist as nothing to do.
+fn ignore_not_found(removal: io::Result<()>) -> io::Result<bool> {
+    match removal {
+        Ok(()) => Ok(true),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(false),
+        Err(err) => Err(err),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_cache(name: &str) -> InputCache {
+        let root = std::env::temp_dir().join(format!("aoc-cache-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        InputCache::new(root)
+    }
+
+    #[test]
+    fn test_cache_round_trip() {
+        let cache = temp_cache("round-trip");
+        assert_eq!(cache.read(2025, 1), None);
+        cache.write(2025, 1, "R49\nL98\n").unwrap();
+        assert_eq!(cache.read(2025, 1), Some("R49\nL98\n".to_string()));
+        fs::remove_dir_all(cache.root()).unwrap();
+    }
+
+    #[test]
+    fn test_profiles_have_their_own_inputs() {
+        let cache = temp_cache("profiles");
+        let alice = cache.clone().profile("alice");
+        cache.write(2025, 1, "R49\n").unwrap();
+        assert_eq!(alice.read(2025, 1), None);
+        alice.write(2025, 1, "L98\n").unwrap();
+        assert_eq!(alice.read(2025, 1), Some("L98\n".to_string()));
+        assert_eq!(cache.read(2025, 1), Some("R49\n".to_string()));
+        assert!(cache
+            .clone()
+            .profile("../alice")
+            .write(2025, 1, "")
+            .is_err());
+        fs::remove_dir_all(cache.root()).unwrap();
+    }
+
+    #[test]
+    fn test_purge_day_and_year() {
+        let cache = temp_cache("purge");
+        cache.write(2025, 1, "one").unwrap();
+        cache.write(2025, 2, "two").unwrap();
+
+        assert!(cache.purge_day(2025, 1).unwrap());
+        assert!(!cache.purge_day(2025, 1).unwrap());
+        assert_eq!(cache.read(2025, 2), Some("two".to_string()));
+
+        assert!(cache.purge_year(2025).unwrap());
+        assert!(!cache.purge_year(2025).unwrap());
+        assert_eq!(cache.read(2025, 2), None);
+        fs::remove_dir_all(cache.root()).unwrap();
+    }
+}
@@ -0,0 +1,87 @@
+use std::time::SystemTime;
+
+use crate::utils::client::unlock_time;
+use crate::utils::error::AocInputError;
+
+/// The year of the first Advent of Code event.
+pub const FIRST_YEAR: u32 = 2015;
+/// The first year with a shorter, 12 day event.
+pub const FIRST_SHORT_YEAR: u32 = 2025;
+
+/// How many puzzles the event for a given year has: 25 for 2015 to 2024, and 12 from 2025
+/// onwards.
+///
+/// # Returns
+/// `None` if there was no event that year.
+///
+/// # Example
+/// ```rust
+/// use aoc_2025::utils::calendar::days_in_event;
+/// assert_eq!(days_in_event(2024), Some(25));
+/// assert_eq!(days_in_event(2025), Some(12));
+/// assert_eq!(days_in_event(2014), None);
+/// ```
+pub fn days_in_event(year: u32) -> Option<u32> {
+    match year {
+        _ if year < FIRST_YEAR => None,
+        _ if year < FIRST_SHORT_YEAR => Some(25),
+        _ => Some(12),
+    }
+}
+
+/// Checks that there is (or will be) a puzzle for the given year and day.
+///
+/// This only rules out impossible puzzles, such as day 13 of 2025 or anything before 2015.
+/// Whether a possible puzzle has unlocked yet is up to
+/// [`AocClient::ensure_unlocked`](crate::utils::client::AocClient::ensure_unlocked).
+///
+/// # Errors
+/// Returns [`AocInputError::NoSuchPuzzle`] if there is no such puzzle.
+pub fn validate_puzzle(year: u32, day: u32) -> Result<(), AocInputError> {
+    match days_in_event(year) {
+        Some(days) if (1..=days).contains(&day) => Ok(()),
+        _ => Err(AocInputError::NoSuchPuzzle { year, day }),
+    }
+}
+
+/// The most recent year whose event has started, i.e. whose first puzzle has unlocked.
+/// Used as the default year by tools which work on any event.
+pub fn latest_event_year() -> u32 {
+    latest_event_year_at(SystemTime::now())
+}
+
+fn latest_event_year_at(now: SystemTime) -> u32 {
+    (FIRST_YEAR..)
+        .take_while(|&year| unlock_time(year, 1) <= now)
+        .last()
+        .unwrap_or(FIRST_YEAR)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_validate_puzzle() {
+        assert!(validate_puzzle(2015, 1).is_ok());
+        assert!(validate_puzzle(2024, 25).is_ok());
+        assert!(validate_puzzle(2025, 12).is_ok());
+        for (year, day) in [(2014, 1), (2024, 0), (2024, 26), (2025, 13), (2025, 25)] {
+            assert!(matches!(
+                validate_puzzle(year, day),
+                Err(AocInputError::NoSuchPuzzle { .. })
+            ));
+        }
+    }
+    #[test]
+    fn test_latest_event_year() {
+        let first_2025_puzzle = unlock_time(2025, 1);
+        assert_eq!(latest_event_year_at(first_2025_puzzle), 2025);
+        assert_eq!(
+            latest_event_year_at(first_2025_puzzle - Duration::from_secs(1)),
+            2024
+        );
+        assert_eq!(latest_event_year_at(unlock_time(2026, 25)), 2026);
+    }
+}
@@ -0,0 +1,332 @@
+use std::env;
+use std::fs::{self, OpenOptions};
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::utils::error::AocInputError;
+use crate::utils::session::{user_config_dir, SessionResolver, SessionToken};
+
+/// User-Agent sent with every request unless overridden. The AoC maintainer asks automated
+/// tools to say where they come from and who to contact about them.
+pub const DEFAULT_USER_AGENT: &str =
+    "github.com/dessertivore/aoc-2025 (contact via GitHub issues on that repository)";
+/// Environment variable which, if set, replaces the User-Agent entirely.
+pub const USER_AGENT_ENV_VAR: &str = "AOC_USER_AGENT";
+/// Where the Advent of Code website lives, unless overridden.
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+/// Environment variable which, if set, replaces the base URL (e.g. to point at a mirror or a
+/// local test server).
+pub const BASE_URL_ENV_VAR: &str = "AOC_BASE_URL";
+/// Default minimum time between two requests to the website, across all processes.
+pub const DEFAULT_MIN_INTERVAL: Duration = Duration::from_secs(5);
+/// Environment variable which, if set, replaces the file the time of the last request is
+/// stored in.
+pub const THROTTLE_FILE_ENV_VAR: &str = "AOC_THROTTLE_FILE";
+
+/// What to do when asked for a puzzle which has not unlocked yet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnlockPolicy {
+    /// Fail straight away with [`AocInputError::NotUnlocked`], without sending a request.
+    Refuse,
+    /// Sleep until the puzzle unlocks, then carry on.
+    Wait,
+}
+
+/// A well-behaved HTTP client for talking to the Advent of Code website.
+///
+/// - Requests go to [`DEFAULT_BASE_URL`], or wherever `AOC_BASE_URL` points.
+/// - Every request carries a User-Agent identifying this project (see
+///   [`DEFAULT_USER_AGENT`]), which can be replaced with `AOC_USER_AGENT`.
+/// - Requests are spaced at least `min_interval` apart. The time of the last request is
+///   stored in a file, so the limit also holds across separate runs of the binaries. The
+///   file is `~/.config/aoc/last_request` (respecting `XDG_CONFIG_HOME`), wherever the
+///   binaries are run from, unless `AOC_THROTTLE_FILE` points somewhere else.
+/// - Puzzles that have not unlocked yet (midnight US-Eastern on their day) are never
+///   requested; depending on the [`UnlockPolicy`] we either refuse or wait for them.
+#[derive(Debug, Clone)]
+pub struct AocClient {
+    http: reqwest::blocking::Client,
+    base_url: String,
+    session: SessionResolver,
+    user_agent: String,
+    min_interval: Duration,
+    throttle_file: PathBuf,
+    unlock_policy: UnlockPolicy,
+}
+
+impl Default for AocClient {
+    fn default() -> Self {
+        AocClient::new()
+    }
+}
+
+impl AocClient {
+    /// A client with the default settings, refusing requests for puzzles not yet unlocked.
+    pub fn new() -> Self {
+        AocClient {
+            http: reqwest::blocking::Client::new(),
+            base_url: env::var(BASE_URL_ENV_VAR)
+                .ok()
+                .filter(|url| !url.trim().is_empty())
+                .map_or_else(
+                    || DEFAULT_BASE_URL.to_string(),
+                    |url| url.trim_end_matches('/').to_string(),
+                ),
+            session: SessionResolver::new(),
+            user_agent: env::var(USER_AGENT_ENV_VAR)
+                .ok()
+                .filter(|agent| !agent.trim().is_empty())
+                .unwrap_or_else(|| DEFAULT_USER_AGENT.to_string()),
+            min_interval: DEFAULT_MIN_INTERVAL,
+            throttle_file: default_throttle_file(),
+            unlock_policy: UnlockPolicy::Refuse,
+        }
+    }
+
+    /// Talks to the website at the given base URL (e.g. `http://127.0.0.1:8080`) instead of
+    /// [`DEFAULT_BASE_URL`].
+    pub fn base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Finds the session token with the given resolver instead of the default one.
+    pub fn session(mut self, session: SessionResolver) -> Self {
+        self.session = session;
+        self
+    }
+
+    /// Sends the given User-Agent instead of the default one.
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.user_agent = user_agent.to_string();
+        self
+    }
+
+    /// Spaces requests at least this far apart.
+    pub fn min_interval(mut self, min_interval: Duration) -> Self {
+        self.min_interval = min_interval;
+        self
+    }
+
+    /// Stores the time of the last request in this file, instead of the default one.
+    pub fn throttle_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.throttle_file = path.into();
+        self
+    }
+
+    /// Chooses what happens when a puzzle has not unlocked yet.
+    pub fn unlock_policy(mut self, unlock_policy: UnlockPolicy) -> Self {
+        self.unlock_policy = unlock_policy;
+        self
+    }
+
+    /// Makes sure the puzzle for the given year and day has unlocked, following this
+    /// client's [`UnlockPolicy`].
+    ///
+    /// # Errors
+    /// Returns [`AocInputError::NotUnlocked`] if the puzzle has not unlocked and the policy
+    /// is [`UnlockPolicy::Refuse`].
+    pub fn ensure_unlocked(&self, year: u32, day: u32) -> Result<(), AocInputError> {
+        let unlocks_at = unlock_time(year, day);
+        if let Ok(wait) = unlocks_at.duration_since(SystemTime::now()) {
+            match self.unlock_policy {
+                UnlockPolicy::Refuse => return Err(AocInputError::NotUnlocked { year, day }),
+                UnlockPolicy::Wait => thread::sleep(wait),
+            }
+        }
+        Ok(())
+    }
+
+    /// Finds the session token to authenticate with.
+    pub fn resolve_session(&self) -> Result<SessionToken, AocInputError> {
+        self.session.resolve()
+    }
+
+    /// The full URL of a page on the website, e.g. `/2025/day/1/input`.
+    pub fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
+
+    /// Sends a `GET` request for a page on the website, with the session cookie if one is
+    /// given.
+    pub fn get(
+        &self,
+        path: &str,
+        session: Option<&SessionToken>,
+    ) -> Result<reqwest::blocking::Response, AocInputError> {
+        let mut request = self.http.get(self.url(path));
+        if let Some(session) = session {
+            request = request.header(reqwest::header::COOKIE, session.cookie_header());
+        }
+        self.send(request)
+    }
+
+    /// Sends a `POST` request to a page on the website, with a form body and the session
+    /// cookie.
+    pub fn post_form(
+        &self,
+        path: &str,
+        session: &SessionToken,
+        form: &[(&str, String)],
+    ) -> Result<reqwest::blocking::Response, AocInputError> {
+        let request = self
+            .http
+            .post(self.url(path))
+            .header(reqwest::header::COOKIE, session.cookie_header())
+            .form(form);
+        self.send(request)
+    }
+
+    fn send(
+        &self,
+        request: reqwest::blocking::RequestBuilder,
+    ) -> Result<reqwest::blocking::Response, AocInputError> {
+        self.throttle()?;
+        Ok(request
+            .header(reqwest::header::USER_AGENT, &self.user_agent)
+            .send()?)
+    }
+
+    /// Sleeps until at least `min_interval` has passed since the last request (made by any
+    /// process sharing the throttle file), then records now as the time of the last request.
+    ///
+    /// The whole check-sleep-write sequence holds an exclusive OS lock on a file next to the
+    /// throttle file, so that two processes cannot both read the same last request time and
+    /// go at once. The lock is released when `lock_file` is dropped, or by the OS if the
+    /// process dies while holding it.
+    fn throttle(&self) -> Result<(), AocInputError> {
+        if let Some(dir) = self.throttle_file.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let lock_file = OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(self.throttle_file.with_extension("lock"))?;
+        lock_file.lock()?;
+
+        let last_request = fs::read_to_string(&self.throttle_file)
+            .ok()
+            .and_then(|millis| millis.trim().parse::<u64>().ok())
+            .map(|millis| UNIX_EPOCH + Duration::from_millis(millis));
+        if let Some(last_request) = last_request {
+            let next_allowed = last_request + self.min_interval;
+            if let Ok(wait) = next_allowed.duration_since(SystemTime::now()) {
+                thread::sleep(wait);
+            }
+        }
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        fs::write(&self.throttle_file, now.as_millis().to_string())?;
+        Ok(())
+    }
+}
+
+/// Where the time of the last request is stored by default: `AOC_THROTTLE_FILE` if it is
+/// set, or else a fixed per-user file, so that every process shares it whichever directory
+/// it is started from.
+fn default_throttle_file() -> PathBuf {
+    env::var_os(THROTTLE_FILE_ENV_VAR)
+        .filter(|path| !path.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| user_config_dir().map(|dir| dir.join("last_request")))
+        .unwrap_or_else(|| env::temp_dir().join("aoc-last-request"))
+}
+
+/// When the puzzle for the given year and day unlocks: midnight US-Eastern (05:00 UTC) on
+/// that day of December.
+///
+/// # Example
+/// ```rust
+/// use aoc_2025::utils::client::unlock_time;
+/// use std::time::{Duration, UNIX_EPOCH};
+/// assert_eq!(unlock_time(2025, 1), UNIX_EPOCH + Duration::from_secs(1764565200));
+/// ```
+pub fn unlock_time(year: u32, day: u32) -> SystemTime {
+    let midnight_utc = days_since_epoch(year, 12, day) * 24 * 60 * 60;
+    UNIX_EPOCH + Duration::from_secs(midnight_utc + 5 * 60 * 60)
+}
+
+/// Number of days from 1970-01-01 to the given date in the proleptic Gregorian calendar.
+///
+/// Uses Howard Hinnant's `days_from_civil` algorithm, shifted so that years start in March
+/// and leap days fall at the end of the year.
+fn days_since_epoch(year: u32, month: u32, day: u32) -> u64 {
+    let year = if month <= 2 { year - 1 } else { year } as u64;
+    let era = year / 400;
+    let year_of_era = year - era * 400;
+    let month_from_march = (month as u64 + 9) % 12;
+    let day_of_year = (153 * month_from_march + 2) / 5 + day as u64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+
+    #[test]
+    fn test_unlock_time() {
+        assert_eq!(
+            unlock_time(2015, 25),
+            UNIX_EPOCH + Duration::from_secs(1451019600)
+        );
+        assert_eq!(days_since_epoch(1970, 1, 1), 0);
+        assert_eq!(days_since_epoch(2000, 3, 1), 11017);
+    }
+
+    #[test]
+    fn test_refuses_locked_puzzle() {
+        let client = AocClient::new();
+        assert!(matches!(
+            client.ensure_unlocked(2999, 1),
+            Err(AocInputError::NotUnlocked { year: 2999, day: 1 })
+        ));
+        assert!(client.ensure_unlocked(2015, 1).is_ok());
+    }
+
+    #[test]
+    fn test_throttle_spaces_requests() {
+        let throttle_file = env::temp_dir().join(format!("aoc-throttle-{}", std::process::id()));
+        let _ = fs::remove_file(&throttle_file);
+        let client = AocClient::new()
+            .min_interval(Duration::from_millis(200))
+            .throttle_file(&throttle_file);
+
+        let start = Instant::now();
+        client.throttle().unwrap();
+        assert!(start.elapsed() < Duration::from_millis(200));
+        client.throttle().unwrap();
+        // The last request time is stored to the millisecond, so allow for rounding
+        assert!(start.elapsed() >= Duration::from_millis(199));
+        fs::remove_file(throttle_file.with_extension("lock")).unwrap();
+        fs::remove_file(throttle_file).unwrap();
+    }
+
+    #[test]
+    fn test_throttle_is_shared_between_clients() {
+        let throttle_file =
+            env::temp_dir().join(format!("aoc-throttle-shared-{}", std::process::id()));
+        let _ = fs::remove_file(&throttle_file);
+        let start = Instant::now();
+        // Separate clients on separate threads stand in for separate processes
+        let handles: Vec<_> = (0..3)
+            .map(|_| {
+                let client = AocClient::new()
+                    .min_interval(Duration::from_millis(100))
+                    .throttle_file(&throttle_file);
+                thread::spawn(move || client.throttle().unwrap())
+            })
+            .collect();
+        handles
+            .into_iter()
+            .for_each(|handle| handle.join().unwrap());
+        assert!(start.elapsed() >= Duration::from_millis(198));
+        fs::remove_file(throttle_file.with_extension("lock")).unwrap();
+        fs::remove_file(throttle_file).unwrap();
+    }
+}
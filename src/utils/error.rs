@@ -0,0 +1,139 @@
+use std::fmt;
+use std::io;
+use std::time::Duration;
+
+use crate::utils::calendar::{days_in_event, FIRST_YEAR};
+
+/// Everything that can go wrong while getting hold of a puzzle input.
+#[derive(Debug)]
+pub enum AocInputError {
+    /// No session cookie could be found, so the website cannot be queried.
+    NoSession,
+    /// A session token was found, but it is malformed. Says what is wrong with it and where
+    /// it came from.
+    InvalidSession(String),
+    /// The website rejected the session cookie, usually because it has expired.
+    ExpiredSession,
+    /// There is no puzzle for this year and day, e.g. day 13 of a 12 day event.
+    NoSuchPuzzle { year: u32, day: u32 },
+    /// There is no such part of a puzzle: every puzzle has parts 1 and 2 only.
+    NoSuchPart { part: u8 },
+    /// The puzzle for this year and day does not exist, or has not unlocked yet.
+    NotUnlocked { year: u32, day: u32 },
+    /// The website asked us to slow down. `retry_after` is how long it asked us to wait,
+    /// if it said.
+    RateLimited { retry_after: Option<Duration> },
+    /// The website replied with a status code we do not know how to handle.
+    UnexpectedStatus { status: u16 },
+    /// The website replied successfully, but with a page we could not make sense of.
+    UnexpectedResponse(String),
+    /// The request could not be sent, or the response could not be read.
+    Transport(reqwest::Error),
+    /// A local file (cookie, cache or test input) could not be read or written.
+    Io(io::Error),
+}
+
+impl AocInputError {
+    /// Whether trying the same request again later might succeed.
+    ///
+    /// Session and unlock problems need a human (or the clock) to intervene first, so they
+    /// are not considered retryable; network hiccups and server errors are.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            AocInputError::RateLimited { .. } | AocInputError::Transport(_) => true,
+            AocInputError::UnexpectedStatus { status } => *status >= 500,
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for AocInputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocInputError::NoSession => write!(f, "no AoC session cookie found"),
+            AocInputError::InvalidSession(reason) => {
+                write!(f, "invalid AoC session token: {}", reason)
+            }
+            AocInputError::ExpiredSession => {
+                write!(
+                    f,
+                    "AoC rejected the session cookie - it has probably expired"
+                )
+            }
+            AocInputError::NoSuchPuzzle { year, day } => match days_in_event(*year) {
+                Some(days) => write!(
+                    f,
+                    "there is no puzzle for {} day {} - that event has days 1 to {}",
+                    year, day, days
+                ),
+                None => write!(
+                    f,
+                    "there is no puzzle for {} day {} - Advent of Code started in {}",
+                    year, day, FIRST_YEAR
+                ),
+            },
+            AocInputError::NoSuchPart { part } => {
+                write!(f, "there is no part {} - puzzles have parts 1 and 2", part)
+            }
+            AocInputError::NotUnlocked { year, day } => {
+                write!(
+                    f,
+                    "puzzle {} day {} does not exist or is not unlocked yet",
+                    year, day
+                )
+            }
+            AocInputError::RateLimited {
+                retry_after: Some(wait),
+            } => write!(f, "rate limited by AoC, retry in {}s", wait.as_secs()),
+            AocInputError::RateLimited { retry_after: None } => write!(f, "rate limited by AoC"),
+            AocInputError::UnexpectedStatus { status } => {
+                write!(f, "AoC responded with unexpected status {}", status)
+            }
+            AocInputError::UnexpectedResponse(body) => {
+                write!(f, "could not understand AoC response: {}", body)
+            }
+            AocInputError::Transport(err) => write!(f, "request to AoC failed: {}", err),
+            AocInputError::Io(err) => write!(f, "I/O error: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for AocInputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AocInputError::Transport(err) => Some(err),
+            AocInputError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for AocInputError {
+    fn from(err: reqwest::Error) -> Self {
+        AocInputError::Transport(err)
+    }
+}
+
+impl From<io::Error> for AocInputError {
+    fn from(err: io::Error) -> Self {
+        AocInputError::Io(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_retryable() {
+        assert!(AocInputError::RateLimited { retry_after: None }.is_retryable());
+        assert!(AocInputError::UnexpectedStatus { status: 503 }.is_retryable());
+        assert!(!AocInputError::UnexpectedStatus { status: 418 }.is_retryable());
+        assert!(!AocInputError::ExpiredSession.is_retryable());
+        assert!(!AocInputError::NotUnlocked {
+            year: 2025,
+            day: 13
+        }
+        .is_retryable());
+    }
+}
@@ -1,5 +1,10 @@
 use reqwest;
-use std::fs;
+use std::time::Duration;
+
+use crate::utils::cache::InputCache;
+use crate::utils::calendar::validate_puzzle;
+use crate::utils::client::AocClient;
+use crate::utils::error::AocInputError;
 
 /// Fetches the Advent of Code input for a given year and day.
 ///
@@ -12,63 +17,141 @@ use std::fs;
 ///
 /// A `String` containing the input for the specified year and day.
 ///
-/// # Panics
+/// # Errors
 ///
-/// This function will panic in the following scenarios:
-/// - If the `testing` configuration is enabled and the year is not 2025.
-/// - If the test input file for the specified day cannot be read.
-/// - If the `cookie.txt` file cannot be read.
-/// - If the HTTP request to fetch the input fails.
-/// - If the HTTP response status is not successful.
+/// Returns an [`AocInputError`] in the following scenarios:
+/// - [`AocInputError::NoSuchPuzzle`] if the event for that year has no such day (see
+///   [`validate_puzzle`]).
+/// - [`AocInputError::Io`] if a freshly fetched input cannot be written to the cache.
+/// - [`AocInputError::NoSession`] or [`AocInputError::InvalidSession`] if no usable session
+///   token can be found (see [`SessionResolver`](crate::utils::session::SessionResolver)).
+/// - [`AocInputError::NotUnlocked`] if the puzzle has not unlocked yet, in which case no
+///   request is sent at all (see [`AocClient`]).
+/// - [`AocInputError::Transport`] if the HTTP request to fetch the input fails.
+/// - [`AocInputError::ExpiredSession`], [`AocInputError::NotUnlocked`],
+///   [`AocInputError::RateLimited`] or [`AocInputError::UnexpectedStatus`] if the HTTP
+///   response status is not successful.
 ///
 /// # Behavior
 ///
-/// - In testing mode (`cfg!(test)`), the function reads the input from a local file
-///   located in the `test-inputs` directory. The file name is expected to follow the
-///   format `day_<day>.txt`. Files used for testing this func should have an impossible
-///   date number (i.e. > 31).
-/// - In non-testing mode, the function reads the session cookie from `cookie.txt`
-///   and uses it to authenticate an HTTP request to the Advent of Code website.
-///   The input is fetched from the URL `https://adventofcode.com/{year}/day/{day}/input`.
+/// - The function first looks for a cached copy of the input under
+///   `inputs/<year>/day_<day>.txt` (`day_<day>.<profile>.txt` with `AOC_PROFILE` set, see
+///   [`InputCache`]) and returns it if present.
+/// - Otherwise, it finds the session token (see
+///   [`SessionResolver`](crate::utils::session::SessionResolver)) and uses it to authenticate
+///   an HTTP request to the Advent of Code website. The input is fetched from the URL
+///   `https://adventofcode.com/{year}/day/{day}/input` (the base URL can be changed, see
+///   [`AocClient`]) and written to the cache, so later
+///   runs do not need to hit the website again.
+///
+/// Solvers should not call this directly; they take an
+/// [`InputSource`](crate::utils::input_source::InputSource) instead, so that tests can feed
+/// them the worked examples.
 ///
 /// # Example
 ///
-/// ```rust
+/// ```rust,no_run
 /// use aoc_2025::utils::get_input;
 ///
-/// #[cfg(test)]
-/// let input = get_input::get_aoc_input(2025, 1);
+/// let input = get_input::get_aoc_input(2025, 1).expect("Failed to get puzzle input");
 /// ```
-pub fn get_aoc_input(year: u32, day: u32) -> String {
-    let testing: bool = cfg!(test); // TODO fix this - no longer works with new structure
-    if testing {
-        if year != 2025 {
-            panic!("Only 2025 test inputs can be found here.")
-        }
-        let file_name: String = format!("../../test-inputs/day_{}.txt", day);
-        let text = fs::read_to_string(file_name).expect("Unable to read text file :(");
-        return text;
+pub fn get_aoc_input(year: u32, day: u32) -> Result<String, AocInputError> {
+    get_aoc_input_with(&AocClient::new(), &InputCache::default(), year, day)
+}
+
+/// Like [`get_aoc_input`], but using the given client and cache instead of the defaults.
+pub fn get_aoc_input_with(
+    client: &AocClient,
+    cache: &InputCache,
+    year: u32,
+    day: u32,
+) -> Result<String, AocInputError> {
+    validate_puzzle(year, day)?;
+    if let Some(cached) = cache.read(year, day) {
+        return Ok(cached);
     }
-    let cookie = fs::read_to_string("cookie.txt")
-        .expect("Failed to read cookie.txt")
-        .trim()
-        .to_string(); // Trim any extra whitespace or newlines
+    let input = fetch_aoc_input(client, year, day)?;
+    cache.write(year, day, &input)?;
+
+    Ok(input)
+}
+
+/// Fetches the Advent of Code input for a given year and day from the website, ignoring
+/// any cached copy, and replaces the cached copy with the freshly downloaded one.
+///
+/// Useful when a cached input has been corrupted, or was saved before the puzzle unlocked.
+///
+/// # Errors
+///
+/// Fails in the same scenarios as [`get_aoc_input`] does when fetching from the website.
+pub fn refresh_aoc_input(year: u32, day: u32) -> Result<String, AocInputError> {
+    refresh_aoc_input_with(&AocClient::new(), &InputCache::default(), year, day)
+}
 
-    let client = reqwest::blocking::Client::new();
-    let response = client
-        .get(format!(
-            "https://adventofcode.com/{year}/day/{day}/input",
-            year = year,
-            day = day,
-        ))
-        .header(reqwest::header::COOKIE, cookie)
-        .send()
-        .expect("Failed to send request!");
+/// Like [`refresh_aoc_input`], but using the given client and cache instead of the defaults.
+pub fn refresh_aoc_input_with(
+    client: &AocClient,
+    cache: &InputCache,
+    year: u32,
+    day: u32,
+) -> Result<String, AocInputError> {
+    validate_puzzle(year, day)?;
+    let input = fetch_aoc_input(client, year, day)?;
+    cache.write(year, day, &input)?;
 
-    if response.status().is_success() {
-        response.text().expect("Failed to read response text!")
-    } else {
-        panic!("Request failed with status: {}", response.status())
+    Ok(input)
+}
+
+/// Downloads the input for a given year and day, without touching the cache.
+fn fetch_aoc_input(client: &AocClient, year: u32, day: u32) -> Result<String, AocInputError> {
+    let session = client.resolve_session()?;
+    client.ensure_unlocked(year, day)?;
+    let response = client.get(
+        &format!("/{year}/day/{day}/input", year = year, day = day),
+        Some(&session),
+    )?;
+
+    check_status(&response, year, Some(day))?;
+    Ok(response.text()?)
+}
+
+/// Maps an unsuccessful response from the AoC website to the matching [`AocInputError`].
+/// `day` is the puzzle the request was for, if it was for one.
+pub(crate) fn check_status(
+    response: &reqwest::blocking::Response,
+    year: u32,
+    day: Option<u32>,
+) -> Result<(), AocInputError> {
+    let retry_after = response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse::<u64>().ok())
+        .map(Duration::from_secs);
+
+    match classify_status(response.status().as_u16(), year, day, retry_after) {
+        Some(err) => Err(err),
+        None => Ok(()),
+    }
+}
+
+/// Works out which error (if any) an HTTP status code from the AoC website represents.
+///
+/// AoC answers `400` when the session cookie is missing or no longer valid, and `404` when
+/// asked for a puzzle which has not unlocked yet. A `404` for anything other than a
+/// puzzle is just unexpected.
+fn classify_status(
+    status: u16,
+    year: u32,
+    day: Option<u32>,
+    retry_after: Option<Duration>,
+) -> Option<AocInputError> {
+    match (status, day) {
+        (200..=299, _) => None,
+        (400 | 401 | 403, _) => Some(AocInputError::ExpiredSession),
+        (404, Some(day)) => Some(AocInputError::NotUnlocked { year, day }),
+        (429, _) => Some(AocInputError::RateLimited { retry_after }),
+        _ => Some(AocInputError::UnexpectedStatus { status }),
     }
 }
 
@@ -76,7 +159,32 @@ pub fn get_aoc_input(year: u32, day: u32) -> String {
 mod tests {
     use super::*;
     #[test]
-    fn test_get_aoc_test_input() {
-        assert_eq!(get_aoc_input(2025, 2025), "This is a test file :D");
+    fn test_classify_status() {
+        assert!(classify_status(200, 2025, Some(1), None).is_none());
+        assert!(matches!(
+            classify_status(400, 2025, Some(1), None),
+            Some(AocInputError::ExpiredSession)
+        ));
+        assert!(matches!(
+            classify_status(404, 2025, Some(13), None),
+            Some(AocInputError::NotUnlocked {
+                year: 2025,
+                day: 13
+            })
+        ));
+        assert!(matches!(
+            classify_status(404, 2025, None, None),
+            Some(AocInputError::UnexpectedStatus { status: 404 })
+        ));
+        assert!(matches!(
+            classify_status(429, 2025, Some(1), Some(Duration::from_secs(60))),
+            Some(AocInputError::RateLimited {
+                retry_after: Some(_)
+            })
+        ));
+        assert!(matches!(
+            classify_status(502, 2025, Some(1), None),
+            Some(AocInputError::UnexpectedStatus { status: 502 })
+        ));
     }
 }
@@ -0,0 +1,436 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::utils::parsing::{numbered_lines, ParseError};
+
+/// A position in a [`Grid`], as `(row, col)`: rows count down from the top line of the
+/// input, and columns count across from the left, both starting at 0.
+pub type Pos = (usize, usize);
+
+/// The four orthogonal directions, as `(row, col)` offsets: up, left, right, down.
+pub const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+/// All eight directions including diagonals, as `(row, col)` offsets, in reading order.
+pub const ALL_DIRECTIONS: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// A rectangular grid of cells, such as the maps many puzzles give as input.
+///
+/// Positions are always `(row, col)` (see [`Pos`]). Cells are stored row by row, and can
+/// be read with [`Grid::get`] (which checks bounds) or by indexing with a position (which
+/// panics when out of bounds).
+///
+/// Displaying a grid writes each cell followed by a newline at the end of every row, so a
+/// grid of `char`s displays as exactly the text it was parsed from.
+///
+/// # Example
+/// ```rust
+/// use aoc_2025::utils::grid::Grid;
+/// let grid = Grid::parse_chars("..@\n@@.\n").unwrap();
+/// assert_eq!(grid[(1, 0)], '@');
+/// assert_eq!(grid.find_all(&'@').collect::<Vec<_>>(), [(0, 2), (1, 0), (1, 1)]);
+/// assert_eq!(grid.to_string(), "..@\n@@.\n");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Parses a grid from text, one row per line, turning each character into a cell with
+    /// `to_cell`. Trailing blank lines are ignored.
+    ///
+    /// # Errors
+    /// Returns a [`ParseError`] if the lines are not all the same length.
+    ///
+    /// # Example
+    /// ```rust
+    /// use aoc_2025::utils::grid::Grid;
+    /// let grid = Grid::parse("#.\n.#", |c| c == '#').unwrap();
+    /// assert_eq!(grid.get((1, 1)), Some(&true));
+    /// assert_eq!(grid.get((2, 0)), None);
+    /// ```
+    pub fn parse(text: &str, mut to_cell: impl FnMut(char) -> T) -> Result<Self, ParseError> {
+        Grid::try_parse(text, |c| Some(to_cell(c)))
+    }
+
+    /// Like [`Grid::parse`], but `to_cell` may reject a character by returning `None`.
+    ///
+    /// # Errors
+    /// Returns a [`ParseError`] pointing at the first rejected character, or at the first
+    /// line which is a different length to the first line.
+    ///
+    /// # Example
+    /// ```rust
+    /// use aoc_2025::utils::grid::Grid;
+    /// let to_cell = |c| match c {
+    ///     '#' => Some(true),
+    ///     '.' => Some(false),
+    ///     _ => None,
+    /// };
+    /// assert!(Grid::try_parse("#.\n.#", to_cell).is_ok());
+    /// assert_eq!(Grid::try_parse("#.\n.x", to_cell).unwrap_err().columns, 1..2);
+    /// ```
+    pub fn try_parse(
+        text: &str,
+        mut to_cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut cells = Vec::new();
+        let mut width = 0;
+        let mut height = 0;
+        for (line_no, line) in numbered_lines(text.trim_end_matches(['\n', '\r'])) {
+            let mut found = 0;
+            for (col, c) in line.chars().enumerate() {
+                let cell = to_cell(c).ok_or_else(|| {
+                    ParseError::new(
+                        line_no,
+                        line,
+                        col..col + 1,
+                        format!("unexpected character {:?}", c),
+                    )
+                })?;
+                cells.push(cell);
+                found += 1;
+            }
+            if line_no == 1 {
+                width = found;
+            } else if found != width {
+                return Err(ParseError::whole_line(
+                    line_no,
+                    line,
+                    format!(
+                        "line is {} characters long, but the grid is {} wide",
+                        found, width
+                    ),
+                ));
+            }
+            height += 1;
+        }
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    /// Builds a grid from cells listed row by row.
+    ///
+    /// # Panics
+    /// Panics if the number of cells is not `width * height`.
+    pub fn from_cells(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "a {}x{} grid needs {} cells",
+            width,
+            height,
+            width * height
+        );
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// The number of columns.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The number of rows.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether the position lies inside the grid.
+    pub fn contains(&self, (row, col): Pos) -> bool {
+        row < self.height && col < self.width
+    }
+
+    /// The cell at the given position, or `None` if it lies outside the grid.
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.0 * self.width + pos.1])
+    }
+
+    /// The cell at the given position for modification, or `None` if it lies outside the
+    /// grid.
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.0 * self.width + pos.1])
+        } else {
+            None
+        }
+    }
+
+    /// Moves from a position by a `(row, col)` offset, returning `None` if that leaves the
+    /// grid.
+    pub fn offset(&self, (row, col): Pos, (d_row, d_col): (isize, isize)) -> Option<Pos> {
+        let pos = (
+            row.checked_add_signed(d_row)?,
+            col.checked_add_signed(d_col)?,
+        );
+        self.contains(pos).then_some(pos)
+    }
+
+    /// The up to four orthogonal neighbours of a position that lie inside the grid.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ORTHOGONAL
+            .into_iter()
+            .filter_map(move |direction| self.offset(pos, direction))
+    }
+
+    /// The up to eight neighbours of a position, including diagonals, that lie inside the
+    /// grid.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ALL_DIRECTIONS
+            .into_iter()
+            .filter_map(move |direction| self.offset(pos, direction))
+    }
+
+    /// Every position in the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    /// Every cell along with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The cells of one row, from left to right.
+    ///
+    /// # Panics
+    /// Panics if the row lies outside the grid.
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    /// Every row, from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on a chunk size of 0, which a grid with no columns would give
+        (0..self.height).map(move |row| self.row(row))
+    }
+
+    /// The cells of one column, from top to bottom.
+    ///
+    /// # Panics
+    /// Panics if the column lies outside the grid.
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            col < self.width,
+            "column {} is outside the {}x{} grid",
+            col,
+            self.width,
+            self.height
+        );
+        self.cells.iter().skip(col).step_by(self.width.max(1))
+    }
+
+    /// Every column, from left to right.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |col| self.column(col))
+    }
+
+    /// The position of every cell equal to `value`, row by row.
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Pos> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(pos, _)| pos)
+    }
+
+    /// The position of the first cell equal to `value`, reading row by row.
+    pub fn find(&self, value: &T) -> Option<Pos>
+    where
+        T: PartialEq,
+    {
+        self.find_all(value).next()
+    }
+
+    /// A grid of the same size with every cell converted by `f`.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Builds a grid of the given size, working out the cell at each position with `f`.
+    fn from_fn(width: usize, height: usize, mut f: impl FnMut(Pos) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |col| (row, col)))
+            .map(&mut f)
+            .collect();
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+}
+
+impl Grid<char> {
+    /// Parses a grid of characters from text, one row per line.
+    ///
+    /// # Errors
+    /// Returns a [`ParseError`] if the lines are not all the same length.
+    pub fn parse_chars(text: &str) -> Result<Self, ParseError> {
+        Grid::parse(text, |c| c)
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// A grid of the given size with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Swaps rows and columns, so that the cell at `(row, col)` moves to `(col, row)`.
+    pub fn transpose(&self) -> Self {
+        Grid::from_fn(self.height, self.width, |(row, col)| {
+            self[(col, row)].clone()
+        })
+    }
+
+    /// Turns the grid a quarter turn clockwise.
+    pub fn rotate_clockwise(&self) -> Self {
+        Grid::from_fn(self.height, self.width, |(row, col)| {
+            self[(self.height - 1 - col, row)].clone()
+        })
+    }
+
+    /// Turns the grid a quarter turn anticlockwise.
+    pub fn rotate_anticlockwise(&self) -> Self {
+        Grid::from_fn(self.height, self.width, |(row, col)| {
+            self[(col, self.width - 1 - row)].clone()
+        })
+    }
+
+    /// Mirrors the grid left to right.
+    pub fn flip_horizontal(&self) -> Self {
+        Grid::from_fn(self.width, self.height, |(row, col)| {
+            self[(row, self.width - 1 - col)].clone()
+        })
+    }
+
+    /// Mirrors the grid top to bottom.
+    pub fn flip_vertical(&self) -> Self {
+        Grid::from_fn(self.width, self.height, |(row, col)| {
+            self[(self.height - 1 - row, col)].clone()
+        })
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos).unwrap_or_else(|| {
+            panic!(
+                "position {:?} is outside the {}x{} grid",
+                pos, self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos).unwrap_or_else(|| {
+            panic!(
+                "position {:?} is outside the {}x{} grid",
+                pos, width, height
+            )
+        })
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = "ab.\n.cd\n";
+
+    #[test]
+    fn test_parse_and_display_round_trip() {
+        let grid = Grid::parse_chars(TEXT).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 2)], 'd');
+        assert_eq!(grid.to_string(), TEXT);
+        let err = Grid::parse_chars("ab\nc\n").unwrap_err();
+        assert_eq!((err.line, err.columns), (2, 0..1));
+    }
+
+    #[test]
+    fn test_bounds_and_neighbours() {
+        let grid = Grid::parse_chars(TEXT).unwrap();
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.get((0, 3)), None);
+        assert_eq!(grid.offset((0, 0), (-1, 0)), None);
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            [(0, 1), (1, 0)]
+        );
+        assert_eq!(
+            grid.neighbours8((0, 1)).collect::<Vec<_>>(),
+            [(0, 0), (0, 2), (1, 0), (1, 1), (1, 2)]
+        );
+    }
+
+    #[test]
+    fn test_rows_columns_and_find() {
+        let grid = Grid::parse_chars(TEXT).unwrap();
+        assert_eq!(grid.row(1), ['.', 'c', 'd']);
+        let columns: Vec<String> = grid.columns().map(|col| col.collect()).collect();
+        assert_eq!(columns, ["a.", "bc", ".d"]);
+        assert_eq!(grid.find_all(&'.').collect::<Vec<_>>(), [(0, 2), (1, 0)]);
+        assert_eq!(grid.find(&'z'), None);
+    }
+
+    #[test]
+    fn test_transformations() {
+        let grid = Grid::parse_chars(TEXT).unwrap();
+        assert_eq!(grid.transpose().to_string(), "a.\nbc\n.d\n");
+        assert_eq!(grid.rotate_clockwise().to_string(), ".a\ncb\nd.\n");
+        assert_eq!(grid.rotate_anticlockwise().to_string(), ".d\nbc\na.\n");
+        assert_eq!(grid.flip_horizontal().to_string(), ".ba\ndc.\n");
+        assert_eq!(grid.flip_vertical().to_string(), ".cd\nab.\n");
+        assert_eq!(
+            grid.rotate_clockwise().rotate_anticlockwise(),
+            grid,
+            "rotations should undo each other"
+        );
+    }
+}
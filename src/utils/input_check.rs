@@ -0,0 +1,290 @@
+use std::fmt;
+use std::ops::Range;
+
+use crate::utils::parsing::{numbered_lines, ParseError};
+
+/// The kinds of problem [`InputCheck`] looks for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IssueKind {
+    /// The input starts with a byte order mark.
+    ByteOrderMark,
+    /// A line holds a tab, other than in the whitespace at the end of the line.
+    Tab,
+    /// A line ends in `\r\n` rather than `\n`.
+    CarriageReturn,
+    /// A line ends in spaces or tabs.
+    TrailingWhitespace,
+    /// A line is a different length to the first line.
+    RaggedRow,
+    /// A line holds a character outside the declared alphabet.
+    UnexpectedChar,
+    /// The input does not end with a newline.
+    MissingFinalNewline,
+}
+
+impl IssueKind {
+    /// Whether [`InputCheck::normalise`] fixes this kind of problem.
+    pub fn is_fixable(self) -> bool {
+        matches!(
+            self,
+            IssueKind::ByteOrderMark
+                | IssueKind::CarriageReturn
+                | IssueKind::TrailingWhitespace
+                | IssueKind::MissingFinalNewline
+        )
+    }
+}
+
+/// A problem found by [`InputCheck::check`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Issue {
+    /// What is wrong.
+    pub kind: IssueKind,
+    /// The line the problem is on, counting from 1.
+    pub line: usize,
+    /// Which characters of the line are at fault, counting from 0.
+    pub columns: Range<usize>,
+    /// The whole text of the offending line, without its line ending.
+    pub text: String,
+    /// A description of the problem.
+    pub message: String,
+}
+
+impl Issue {
+    fn new(
+        kind: IssueKind,
+        line: usize,
+        text: &str,
+        columns: Range<usize>,
+        message: impl Into<String>,
+    ) -> Self {
+        Issue {
+            kind,
+            line,
+            columns,
+            text: text.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// The issue as a [`ParseError`], for reporting alongside other problems with the
+    /// input.
+    pub fn to_parse_error(&self) -> ParseError {
+        ParseError::new(self.line, &self.text, self.columns.clone(), &self.message)
+    }
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_parse_error())
+    }
+}
+
+/// Checks puzzle input for things which trip up solvers: a byte order mark, tabs,
+/// Windows line endings, trailing whitespace, a missing final newline and, if asked for,
+/// rows of different lengths or characters outside an expected alphabet.
+///
+/// # Example
+/// ```rust
+/// use aoc_2025::utils::input_check::{InputCheck, IssueKind};
+/// let check = InputCheck::new().rectangular(true).alphabet("@.");
+/// let issues = check.check("..@@\r\n@@@.@\r\n");
+/// let kinds: Vec<IssueKind> = issues.iter().map(|issue| issue.kind).collect();
+/// assert_eq!(
+///     kinds,
+///     [IssueKind::CarriageReturn, IssueKind::CarriageReturn, IssueKind::RaggedRow]
+/// );
+/// assert_eq!(InputCheck::normalise("\u{feff}..@@ \r\n@@@."), "..@@\n@@@.\n");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct InputCheck {
+    alphabet: Option<Vec<char>>,
+    rectangular: bool,
+}
+
+impl InputCheck {
+    /// A check for the problems which apply to any input.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Also reports any character (other than line endings) which is not in `alphabet`.
+    pub fn alphabet(mut self, alphabet: &str) -> Self {
+        self.alphabet = Some(alphabet.chars().collect());
+        self
+    }
+
+    /// Also reports any line which is not the same length as the first, for inputs which
+    /// should be a grid.
+    pub fn rectangular(mut self, rectangular: bool) -> Self {
+        self.rectangular = rectangular;
+        self
+    }
+
+    /// Finds every problem with `input`, in the order they appear.
+    pub fn check(&self, input: &str) -> Vec<Issue> {
+        let mut issues = Vec::new();
+        let input = match input.strip_prefix('\u{feff}') {
+            Some(rest) => {
+                let first = rest.lines().next().unwrap_or_default();
+                issues.push(Issue::new(
+                    IssueKind::ByteOrderMark,
+                    1,
+                    first,
+                    0..0,
+                    "input starts with a byte order mark",
+                ));
+                rest
+            }
+            None => input,
+        };
+
+        let mut raw_lines = input.split('\n');
+        let mut width = None;
+        for (line_no, line) in numbered_lines(input) {
+            let raw = raw_lines.next().unwrap_or_default();
+            let len = line.chars().count();
+            if raw.ends_with('\r') {
+                issues.push(Issue::new(
+                    IssueKind::CarriageReturn,
+                    line_no,
+                    line,
+                    len..len,
+                    "line ends in \\r\\n",
+                ));
+            }
+            // Tabs at the end of the line are reported as trailing whitespace instead
+            let trimmed = line.trim_end_matches([' ', '\t']).chars().count();
+            for (col, _) in line
+                .chars()
+                .take(trimmed)
+                .enumerate()
+                .filter(|(_, c)| *c == '\t')
+            {
+                issues.push(Issue::new(
+                    IssueKind::Tab,
+                    line_no,
+                    line,
+                    col..col + 1,
+                    "line holds a tab",
+                ));
+            }
+            if trimmed < len {
+                issues.push(Issue::new(
+                    IssueKind::TrailingWhitespace,
+                    line_no,
+                    line,
+                    trimmed..len,
+                    "line ends in whitespace",
+                ));
+            }
+            if let Some(alphabet) = &self.alphabet {
+                for (col, c) in line.chars().enumerate() {
+                    if !alphabet.contains(&c) {
+                        issues.push(Issue::new(
+                            IssueKind::UnexpectedChar,
+                            line_no,
+                            line,
+                            col..col + 1,
+                            format!("unexpected {:?}", c),
+                        ));
+                    }
+                }
+            }
+            if self.rectangular {
+                match width {
+                    None => width = Some(len),
+                    Some(width) if width != len => issues.push(Issue::new(
+                        IssueKind::RaggedRow,
+                        line_no,
+                        line,
+                        0..len,
+                        format!("line is {} characters long, not {}", len, width),
+                    )),
+                    Some(_) => {}
+                }
+            }
+        }
+
+        if !input.is_empty() && !input.ends_with('\n') {
+            let (line_no, last) = numbered_lines(input).last().unwrap_or((1, ""));
+            let len = last.chars().count();
+            issues.push(Issue::new(
+                IssueKind::MissingFinalNewline,
+                line_no,
+                last,
+                len..len,
+                "input does not end with a newline",
+            ));
+        }
+        issues
+    }
+
+    /// Fixes the problems which can be fixed without changing what the input means:
+    /// removes any byte order mark, turns `\r\n` into `\n`, removes trailing spaces and
+    /// tabs from each line, and makes sure the input ends with a single newline.
+    ///
+    /// Tabs, ragged rows and unexpected characters are left alone.
+    pub fn normalise(input: &str) -> String {
+        let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+        let mut normalised = String::with_capacity(input.len() + 1);
+        for line in input.lines() {
+            normalised.push_str(line.trim_end_matches([' ', '\t']));
+            normalised.push('\n');
+        }
+        normalised
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_reports_each_problem() {
+        let check = InputCheck::new().alphabet("S^.").rectangular(true);
+        let issues = check.check("\u{feff}..S.\n.\t. \r\n.x..");
+        let found: Vec<(IssueKind, usize, Range<usize>)> = issues
+            .iter()
+            .map(|issue| (issue.kind, issue.line, issue.columns.clone()))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                (IssueKind::ByteOrderMark, 1, 0..0),
+                (IssueKind::CarriageReturn, 2, 4..4),
+                (IssueKind::Tab, 2, 1..2),
+                (IssueKind::TrailingWhitespace, 2, 3..4),
+                (IssueKind::UnexpectedChar, 2, 1..2),
+                (IssueKind::UnexpectedChar, 2, 3..4),
+                (IssueKind::UnexpectedChar, 3, 1..2),
+                (IssueKind::MissingFinalNewline, 3, 4..4),
+            ]
+        );
+        assert!(InputCheck::new().check("").is_empty());
+    }
+
+    #[test]
+    fn test_normalise_fixes_what_it_can() {
+        let input = "\u{feff}123 328 \r\n 45 64\t\r\n+  *";
+        let normalised = InputCheck::normalise(input);
+        assert_eq!(normalised, "123 328\n 45 64\n+  *\n");
+        assert!(InputCheck::new().check(&normalised).is_empty());
+        assert!(InputCheck::new()
+            .check(input)
+            .iter()
+            .all(|issue| issue.kind.is_fixable()));
+    }
+
+    #[test]
+    fn test_fixtures_only_have_fixable_issues() {
+        let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("test-inputs/2025");
+        for entry in std::fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            let input = std::fs::read_to_string(&path).unwrap();
+            for issue in InputCheck::new().check(&input) {
+                assert!(issue.kind.is_fixable(), "{}: {}", path.display(), issue);
+            }
+        }
+    }
+}
@@ -0,0 +1,152 @@
+use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
+
+use crate::utils::cache::InputCache;
+use crate::utils::calendar::validate_puzzle;
+use crate::utils::error::AocInputError;
+use crate::utils::get_input::get_aoc_input;
+
+/// Where a solver should get its puzzle input from.
+///
+/// Every day's entry point takes an `InputSource` rather than fetching its own input, so
+/// that tests can feed in the worked examples while `main` uses the real puzzle input.
+///
+/// # Example
+/// ```rust
+/// use aoc_2025::utils::input_source::InputSource;
+/// let source = InputSource::Inline("R49\nL98".to_string());
+/// assert_eq!(source.load().unwrap(), "R49\nL98");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// The real puzzle input, read from the on-disk cache or downloaded from the website
+    /// (see [`get_aoc_input`]).
+    Live { year: u32, day: u32 },
+    /// The real puzzle input, read from the on-disk cache only. Never touches the network,
+    /// so fails if the input has not been downloaded before.
+    Cache { year: u32, day: u32 },
+    /// One of the worked examples in `test-inputs/<year>` (see [`InputSource::example`]).
+    Example(PathBuf),
+    /// Any other file on disk, such as an input given on the command line.
+    File(PathBuf),
+    /// Input held in memory, handy for small hand-written test cases.
+    Inline(String),
+    /// Whatever is piped into the program on standard input.
+    Stdin,
+}
+
+impl InputSource {
+    /// The worked example for a given year and day, stored in this repository's
+    /// `test-inputs` directory as `<year>/day_<day>.txt`.
+    ///
+    /// The path is anchored to the crate root, so it works no matter which directory the
+    /// tests are run from.
+    pub fn example(year: u32, day: u32) -> Self {
+        InputSource::Example(example_dir(year).join(format!("day_{}.txt", day)))
+    }
+
+    /// The worked example for one part of a puzzle, for days where part 2 comes with a
+    /// different example to part 1. Stored as `<year>/day_<day>_part_<part>.txt`.
+    pub fn example_part(year: u32, day: u32, part: u8) -> Self {
+        InputSource::Example(example_dir(year).join(format!("day_{}_part_{}.txt", day, part)))
+    }
+
+    /// Reads the whole input from this source.
+    ///
+    /// # Errors
+    /// Returns an [`AocInputError`] if the input cannot be fetched or read. A cache miss
+    /// for [`InputSource::Cache`] is reported as an [`AocInputError::Io`] `NotFound` error,
+    /// and a year and day with no puzzle as [`AocInputError::NoSuchPuzzle`].
+    pub fn load(&self) -> Result<String, AocInputError> {
+        match self {
+            InputSource::Live { year, day } => get_aoc_input(*year, *day),
+            InputSource::Cache { year, day } => {
+                validate_puzzle(*year, *day)?;
+                let cache = InputCache::default();
+                cache.read(*year, *day).ok_or_else(|| {
+                    AocInputError::Io(io::Error::new(
+                        io::ErrorKind::NotFound,
+                        format!("{} is not cached", cache.path_for(*year, *day).display()),
+                    ))
+                })
+            }
+            InputSource::Example(path) => Ok(fs::read_to_string(path)?),
+            InputSource::File(path) => fs::read_to_string(path).map_err(|err| {
+                AocInputError::Io(io::Error::new(
+                    err.kind(),
+                    format!("{}: {}", path.display(), err),
+                ))
+            }),
+            InputSource::Inline(text) => Ok(text.clone()),
+            InputSource::Stdin => {
+                let mut text = String::new();
+                io::stdin().read_to_string(&mut text)?;
+                Ok(text)
+            }
+        }
+    }
+}
+
+/// Where the worked examples for a given year live.
+fn example_dir(year: u32) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test-inputs")
+        .join(year.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_example() {
+        assert!(InputSource::example(2025, 1)
+            .load()
+            .unwrap()
+            .starts_with("L68\n"));
+        assert!(InputSource::example_part(2025, 11, 2)
+            .load()
+            .unwrap()
+            .starts_with("svr: "));
+    }
+
+    #[test]
+    fn test_load_rejects_impossible_day() {
+        assert!(matches!(
+            InputSource::Cache {
+                year: 2025,
+                day: 25
+            }
+            .load(),
+            Err(AocInputError::NoSuchPuzzle {
+                year: 2025,
+                day: 25
+            })
+        ));
+    }
+
+    #[test]
+    fn test_load_missing_example() {
+        assert!(matches!(
+            InputSource::Example(PathBuf::from("does/not/exist.txt")).load(),
+            Err(AocInputError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn test_load_missing_file_names_it() {
+        let err = InputSource::File(PathBuf::from("does/not/exist.txt"))
+            .load()
+            .unwrap_err();
+        assert!(err.to_string().contains("does/not/exist.txt"), "{}", err);
+    }
+
+    #[test]
+    fn test_load_inline() {
+        assert_eq!(
+            InputSource::Inline("1,2,3".to_string()).load().unwrap(),
+            "1,2,3"
+        );
+    }
+}
@@ -0,0 +1,424 @@
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::fs;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+
+use crate::utils::calendar::days_in_event;
+use crate::utils::client::AocClient;
+use crate::utils::error::AocInputError;
+use crate::utils::get_input::check_status;
+use crate::utils::session::{profile_from_env, validate_profile};
+
+/// Environment variable holding the id of the private leaderboard to show by default.
+pub const LEADERBOARD_ENV_VAR: &str = "AOC_LEADERBOARD";
+/// Default directory (relative to the working directory) where downloaded leaderboards are
+/// kept.
+pub const DEFAULT_LEADERBOARD_DIR: &str = "leaderboards";
+/// How long a downloaded leaderboard is reused before asking the website again. The AoC
+/// maintainer asks that private leaderboards are not fetched more often than this.
+pub const LEADERBOARD_REFRESH_INTERVAL: Duration = Duration::from_secs(15 * 60);
+
+/// A private leaderboard, as served by the website's JSON API.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Leaderboard {
+    /// The year of the event, e.g. `"2025"`.
+    pub event: String,
+    /// The member id of whoever created the leaderboard.
+    pub owner_id: u64,
+    /// Every member of the leaderboard, keyed by their member id.
+    pub members: BTreeMap<u64, Member>,
+}
+
+/// One member of a private leaderboard.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    /// The member's display name, or `None` if they are anonymous.
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u64,
+    /// When the member last earned a star, in seconds since the Unix epoch (`0` if never).
+    #[serde(default)]
+    pub last_star_ts: u64,
+    /// When each star was earned, keyed by day and then by part.
+    #[serde(default)]
+    pub completion_day_level: BTreeMap<u32, BTreeMap<u8, Star>>,
+}
+
+/// A star earned by a member.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Star {
+    /// When the star was earned, in seconds since the Unix epoch.
+    pub get_star_ts: u64,
+}
+
+impl Member {
+    /// The name to show for the member, matching how the website shows anonymous users.
+    pub fn display_name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonymous user #{})", self.id),
+        }
+    }
+
+    /// How many stars (0, 1 or 2) the member has earned on the given day.
+    pub fn stars_on(&self, day: u32) -> usize {
+        self.completion_day_level
+            .get(&day)
+            .map_or(0, |parts| parts.len())
+    }
+}
+
+impl Leaderboard {
+    /// The members in leaderboard order: highest local score first, then most stars, then
+    /// whoever got their last star first.
+    pub fn ranked(&self) -> Vec<&Member> {
+        let mut members: Vec<&Member> = self.members.values().collect();
+        members.sort_by(|a, b| {
+            b.local_score
+                .cmp(&a.local_score)
+                .then(b.stars.cmp(&a.stars))
+                .then(a.last_star_ts.cmp(&b.last_star_ts))
+                .then(a.id.cmp(&b.id))
+        });
+        members
+    }
+
+    /// Renders the leaderboard as a table for the terminal, followed by when each member
+    /// earned each of their stars.
+    ///
+    /// In the table, each day is shown as `*` for both stars, `+` for the first star only,
+    /// and `.` for no stars.
+    pub fn render(&self) -> String {
+        let days = 1..=self.days();
+        let ranked = self.ranked();
+        let mut out = String::new();
+
+        let padding = " ".repeat(12);
+        let tens: String = days
+            .clone()
+            .map(|day| match day / 10 {
+                0 => ' ',
+                tens => char::from_digit(tens, 10).unwrap_or('?'),
+            })
+            .collect();
+        let units: String = days
+            .clone()
+            .map(|day| char::from_digit(day % 10, 10).unwrap_or('?'))
+            .collect();
+        let _ = writeln!(out, "{}{}", padding, tens.trim_end());
+        let _ = writeln!(out, "{:>4} {:>5}  {}  Name", "Rank", "Score", units);
+
+        for (rank, member) in ranked.iter().enumerate() {
+            let stars: String = days
+                .clone()
+                .map(|day| match member.stars_on(day) {
+                    0 => '.',
+                    1 => '+',
+                    _ => '*',
+                })
+                .collect();
+            let _ = writeln!(
+                out,
+                "{:>3}) {:>5}  {}  {} ({} stars)",
+                rank + 1,
+                member.local_score,
+                stars,
+                member.display_name(),
+                member.stars
+            );
+        }
+
+        for member in ranked.iter().filter(|member| member.stars > 0) {
+            let _ = writeln!(out, "\n{}", member.display_name());
+            for (day, parts) in &member.completion_day_level {
+                let times: Vec<String> = parts
+                    .iter()
+                    .map(|(part, star)| {
+                        format!("part {} at {}", part, format_timestamp(star.get_star_ts))
+                    })
+                    .collect();
+                let _ = writeln!(out, "  Day {:>2}: {}", day, times.join(", "));
+            }
+        }
+
+        out
+    }
+
+    /// How many days the event runs for (see [`days_in_event`]). Any day a member has a
+    /// star for is always included.
+    fn days(&self) -> u32 {
+        let event_days = self
+            .event
+            .parse::<u32>()
+            .ok()
+            .and_then(days_in_event)
+            .unwrap_or(12);
+        self.members
+            .values()
+            .flat_map(|member| member.completion_day_level.keys().copied())
+            .chain([event_days])
+            .max()
+            .unwrap_or(event_days)
+    }
+}
+
+/// Fetches a private leaderboard for a given year, reusing the cached copy if it was
+/// downloaded less than [`LEADERBOARD_REFRESH_INTERVAL`] ago.
+///
+/// # Parameters
+///
+/// * `year` - The year of the Advent of Code event.
+/// * `board_id` - The id of the leaderboard, i.e. the number in its URL (which is also the
+///   member id of its owner).
+///
+/// # Errors
+///
+/// Fails in the same scenarios as
+/// [`get_aoc_input`](crate::utils::get_input::get_aoc_input) does when fetching from the
+/// website, and with [`AocInputError::UnexpectedResponse`] if the website does not send back
+/// a leaderboard, which happens when the board does not exist or you are not a member.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use aoc_2025::utils::leaderboard::fetch_leaderboard;
+///
+/// let leaderboard = fetch_leaderboard(2025, 123456).expect("Failed to fetch leaderboard");
+/// print!("{}", leaderboard.render());
+/// ```
+pub fn fetch_leaderboard(year: u32, board_id: u64) -> Result<Leaderboard, AocInputError> {
+    fetch_leaderboard_with(
+        &AocClient::new(),
+        &LeaderboardCache::default(),
+        year,
+        board_id,
+    )
+}
+
+/// Like [`fetch_leaderboard`], but using the given client and cache instead of the
+/// defaults.
+pub fn fetch_leaderboard_with(
+    client: &AocClient,
+    cache: &LeaderboardCache,
+    year: u32,
+    board_id: u64,
+) -> Result<Leaderboard, AocInputError> {
+    let path = cache.checked_path_for(year, board_id)?;
+    let cache_age = fs::metadata(&path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .and_then(|modified| SystemTime::now().duration_since(modified).ok());
+    if cache_age.is_some_and(|age| age < LEADERBOARD_REFRESH_INTERVAL) {
+        if let Some(leaderboard) = fs::read_to_string(&path)
+            .ok()
+            .and_then(|json| parse_leaderboard(&json).ok())
+        {
+            return Ok(leaderboard);
+        }
+    }
+
+    let session = client.resolve_session()?;
+    let response = client.get(
+        &format!(
+            "/{year}/leaderboard/private/view/{board_id}.json",
+            year = year,
+            board_id = board_id
+        ),
+        Some(&session),
+    )?;
+    if response.status() == reqwest::StatusCode::NOT_FOUND {
+        return Err(AocInputError::UnexpectedResponse(format!(
+            "there is no {} event, or no leaderboard {}",
+            year, board_id
+        )));
+    }
+    check_status(&response, year, None)?;
+    let json = response.text()?;
+    let leaderboard = parse_leaderboard(&json)?;
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let tmp_path = path.with_extension("json.tmp");
+    fs::write(&tmp_path, &json)?;
+    fs::rename(tmp_path, &path)?;
+
+    Ok(leaderboard)
+}
+
+/// Where downloaded leaderboards are kept, one file per year, board and profile.
+///
+/// A leaderboard is stored as `<root>/<year>/<board_id>.json`, or
+/// `<root>/<year>/<board_id>.<profile>.json` for a named profile (see
+/// [`SessionResolver`](crate::utils::session::SessionResolver)), since each account sees
+/// only the boards it is a member of. They are kept apart from the
+/// [`InputCache`](crate::utils::cache::InputCache), so purging cached inputs leaves them be.
+///
+/// # Example
+/// ```rust
+/// use aoc_2025::utils::leaderboard::LeaderboardCache;
+/// let cache = LeaderboardCache::new("leaderboards").profile("alice");
+/// assert!(cache.path_for(2025, 123).ends_with("leaderboards/2025/123.alice.json"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct LeaderboardCache {
+    root: PathBuf,
+    profile: Option<String>,
+}
+
+impl Default for LeaderboardCache {
+    /// A cache in the default directory, for the profile named by `AOC_PROFILE` if that is
+    /// set.
+    fn default() -> Self {
+        LeaderboardCache {
+            root: PathBuf::from(DEFAULT_LEADERBOARD_DIR),
+            profile: profile_from_env(),
+        }
+    }
+}
+
+impl LeaderboardCache {
+    /// A cache rooted at the given directory, for the default profile.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        LeaderboardCache {
+            root: root.into(),
+            profile: None,
+        }
+    }
+
+    /// Keeps the leaderboards for the given named profile instead of the default one.
+    pub fn profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+
+    /// The path the given leaderboard is (or would be) cached at.
+    pub fn path_for(&self, year: u32, board_id: u64) -> PathBuf {
+        let file = match &self.profile {
+            Some(profile) => format!("{}.{}.json", board_id, profile),
+            None => format!("{}.json", board_id),
+        };
+        self.root.join(year.to_string()).join(file)
+    }
+
+    fn checked_path_for(&self, year: u32, board_id: u64) -> Result<PathBuf, AocInputError> {
+        if let Some(profile) = &self.profile {
+            validate_profile(profile)?;
+        }
+        Ok(self.path_for(year, board_id))
+    }
+}
+
+fn parse_leaderboard(json: &str) -> Result<Leaderboard, AocInputError> {
+    serde_json::from_str(json).map_err(|err| {
+        AocInputError::UnexpectedResponse(format!(
+            "not a leaderboard ({}) - check the board id, and that you are a member of it",
+            err
+        ))
+    })
+}
+
+/// Formats seconds since the Unix epoch as a UTC date and time, e.g.
+/// `2025-12-01 05:12:34 UTC`.
+fn format_timestamp(timestamp: u64) -> String {
+    let (days, seconds) = (timestamp / 86400, timestamp % 86400);
+
+    // Howard Hinnant's `civil_from_days`, the inverse of `days_since_epoch` in the client
+    let z = days + 719468;
+    let era = z / 146097;
+    let day_of_era = z - era * 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_from_march = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_from_march + 2) / 5 + 1;
+    let month = if month_from_march < 10 {
+        month_from_march + 3
+    } else {
+        month_from_march - 9
+    };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02} UTC",
+        year,
+        month,
+        day,
+        seconds / 3600,
+        seconds % 3600 / 60,
+        seconds % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const JSON: &str = r#"{
+        "event": "2025",
+        "owner_id": 1,
+        "members": {
+            "1": {"id": 1, "name": "Alice", "stars": 3, "local_score": 10, "global_score": 0,
+                  "last_star_ts": 1764567000,
+                  "completion_day_level": {
+                      "1": {"1": {"get_star_ts": 1764565500, "star_index": 0},
+                            "2": {"get_star_ts": 1764566000, "star_index": 3}},
+                      "2": {"1": {"get_star_ts": 1764567000, "star_index": 9}}
+                  }},
+            "2": {"id": 2, "name": null, "stars": 0, "local_score": 0, "global_score": 0,
+                  "last_star_ts": 0, "completion_day_level": {}},
+            "3": {"id": 3, "name": "Bob", "stars": 2, "local_score": 10, "global_score": 0,
+                  "last_star_ts": 1764566500,
+                  "completion_day_level": {
+                      "1": {"1": {"get_star_ts": 1764566400, "star_index": 1},
+                            "2": {"get_star_ts": 1764566500, "star_index": 4}}
+                  }}
+        }
+    }"#;
+
+    #[test]
+    fn test_ranked() {
+        let leaderboard = parse_leaderboard(JSON).unwrap();
+        let names: Vec<String> = leaderboard
+            .ranked()
+            .iter()
+            .map(|member| member.display_name())
+            .collect();
+        assert_eq!(names, ["Alice", "Bob", "(anonymous user #2)"]);
+    }
+
+    #[test]
+    fn test_render() {
+        let rendered = parse_leaderboard(JSON).unwrap().render();
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert_eq!(lines[0], "                     111");
+        assert_eq!(lines[1], "Rank Score  123456789012  Name");
+        assert_eq!(lines[2], "  1)    10  *+..........  Alice (3 stars)");
+        assert_eq!(lines[3], "  2)    10  *...........  Bob (2 stars)");
+        assert_eq!(
+            lines[4],
+            "  3)     0  ............  (anonymous user #2) (0 stars)"
+        );
+        assert!(rendered.contains(
+            "  Day  1: part 1 at 2025-12-01 05:05:00 UTC, part 2 at 2025-12-01 05:13:20 UTC"
+        ));
+    }
+
+    #[test]
+    fn test_parse_rejects_html() {
+        assert!(matches!(
+            parse_leaderboard("<html>Log in</html>"),
+            Err(AocInputError::UnexpectedResponse(_))
+        ));
+    }
+
+    #[test]
+    fn test_format_timestamp() {
+        assert_eq!(format_timestamp(0), "1970-01-01 00:00:00 UTC");
+        assert_eq!(format_timestamp(951782400), "2000-02-29 00:00:00 UTC");
+        assert_eq!(format_timestamp(1764565200), "2025-12-01 05:00:00 UTC");
+    }
+}
@@ -1,2 +1,14 @@
+pub mod answers;
+pub mod cache;
+pub mod calendar;
+pub mod client;
+pub mod error;
 pub mod get_input;
+pub mod grid;
+pub mod input_check;
+pub mod input_source;
+pub mod leaderboard;
 pub mod parsing;
+pub mod puzzle;
+pub mod session;
+pub mod submit;
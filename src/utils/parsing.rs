@@ -1,3 +1,9 @@
+use std::fmt;
+use std::ops::Range;
+use std::str::FromStr;
+
+use crate::utils::grid::Pos;
+
 /// Splits the given text by line breaks.
 ///
 /// # Parameters
@@ -15,7 +21,28 @@
 /// println!("{:?}",result)
 /// ```
 pub fn split_lines(text_to_split: String) -> Vec<String> {
-    text_to_split.lines().map(|line| line.to_string()).collect()
+    lines(&text_to_split).map(str::to_string).collect()
+}
+
+/// Lazily splits the given text by line breaks, borrowing each line from `text`.
+///
+/// Prefer this over [`split_lines`] when the lines only need to be read, as no
+/// `String` is allocated per line. Both `\n` and `\r\n` line endings are handled.
+///
+/// # Parameters
+/// - `text`: The input string to be split.
+///
+/// # Returns
+/// An iterator of `&str` slices of `text`, one per line.
+///
+/// # Example
+/// ```rust
+/// use aoc_2025::utils::parsing;
+/// let result: Vec<&str> = parsing::lines("apple\r\nbanana\ncherry").collect();
+/// assert_eq!(result, ["apple", "banana", "cherry"]);
+/// ```
+pub fn lines(text: &str) -> impl Iterator<Item = &str> {
+    text.lines()
 }
 
 /// Splits the given text into an iterator of strings based on the specified delimiter.
@@ -36,12 +63,1025 @@ pub fn split_lines(text_to_split: String) -> Vec<String> {
 /// let result: Vec<String> = parsing::split_string_by_specified_char(text, delimiter);
 /// ```
 pub fn split_string_by_specified_char(text_to_split: String, to_split_by: &str) -> Vec<String> {
-    let iterator = text_to_split
-        .split(to_split_by)
-        .map(|x| x.to_string())
+    split_by(&text_to_split, to_split_by)
+        .map(str::to_string)
+        .collect()
+}
+
+/// Lazily splits the given text on the specified delimiter, borrowing each part from
+/// `text`.
+///
+/// Prefer this over [`split_string_by_specified_char`] when the parts only need to be
+/// read, as no `String` is allocated per part.
+///
+/// # Parameters
+/// - `text`: The input string to be split.
+/// - `to_split_by`: The delimiter used to split the input string.
+///
+/// # Returns
+/// An iterator of `&str` slices of `text`, split by the specified delimiter.
+///
+/// # Example
+/// ```rust
+/// use aoc_2025::utils::parsing;
+/// let result: Vec<&str> = parsing::split_by("apple,banana,cherry", ",").collect();
+/// assert_eq!(result, ["apple", "banana", "cherry"]);
+/// ```
+pub fn split_by<'a>(text: &'a str, to_split_by: &'a str) -> impl Iterator<Item = &'a str> {
+    text.split(to_split_by)
+}
+
+/// An integer type which [`ints`] and friends can pull out of a line of text.
+///
+/// Implemented for every built-in integer type. `SIGNED` decides whether a `-` directly in
+/// front of a number is read as a minus sign.
+pub trait ScanInt: FromStr + Copy {
+    const SIGNED: bool;
+}
+
+macro_rules! impl_scan_int {
+    ($signed:expr => $($t:ty),*) => {
+        $(impl ScanInt for $t {
+            const SIGNED: bool = $signed;
+        })*
+    };
+}
+
+impl_scan_int!(true => i8, i16, i32, i64, i128, isize);
+impl_scan_int!(false => u8, u16, u32, u64, u128, usize);
+
+/// Why integers could not be pulled out of a line of text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExtractIntsError {
+    /// A different number of integers was found to the number asked for.
+    WrongCount {
+        expected: usize,
+        found: usize,
+        line: String,
+    },
+    /// A number was too big (or too small) for the integer type asked for.
+    OutOfRange { number: String, line: String },
+}
+
+impl fmt::Display for ExtractIntsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExtractIntsError::WrongCount {
+                expected,
+                found,
+                line,
+            } => write!(
+                f,
+                "expected {} integers but found {} in {:?}",
+                expected, found, line
+            ),
+            ExtractIntsError::OutOfRange { number, line } => {
+                write!(f, "{} is out of range in {:?}", number, line)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ExtractIntsError {}
+
+impl ExtractIntsError {
+    /// Turns this into a [`ParseError`] pointing at the problem within line `line_no` of
+    /// the input, whose text is `line`.
+    pub fn at_line(self, line_no: usize, line: &str) -> ParseError {
+        match self {
+            ExtractIntsError::WrongCount {
+                expected, found, ..
+            } => ParseError::whole_line(
+                line_no,
+                line,
+                format!("expected {} integers but found {}", expected, found),
+            ),
+            ExtractIntsError::OutOfRange { number, .. } => match line.find(&number) {
+                Some(start) => ParseError::new(
+                    line_no,
+                    line,
+                    char_offset(line, start)..char_offset(line, start + number.len()),
+                    format!("{} is out of range", number),
+                ),
+                None => {
+                    ParseError::whole_line(line_no, line, format!("{} is out of range", number))
+                }
+            },
+        }
+    }
+}
+
+/// Pulls every integer out of a line of text, in order, ignoring everything in between.
+///
+/// For signed types, a `-` is a minus sign when it comes directly before a digit and does
+/// not follow a digit, so `"x=-3"` gives `-3` while the range `"5-10"` gives `5` and `10`.
+/// For unsigned types, a `-` is never part of a number.
+///
+/// # Errors
+/// Returns [`ExtractIntsError::OutOfRange`] if a number does not fit in `T`.
+///
+/// # Example
+/// ```rust
+/// use aoc_2025::utils::parsing::ints;
+/// assert_eq!(ints::<i32>("p=0,-4 v=3-5").unwrap(), [0, -4, 3, 5]);
+/// assert_eq!(ints::<u64>("11-22,95-115").unwrap(), [11, 22, 95, 115]);
+/// ```
+pub fn ints<T: ScanInt>(line: &str) -> Result<Vec<T>, ExtractIntsError> {
+    let bytes = line.as_bytes();
+    let mut numbers = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+        let negative =
+            T::SIGNED && i > 0 && bytes[i - 1] == b'-' && (i < 2 || !bytes[i - 2].is_ascii_digit());
+        let start = if negative { i - 1 } else { i };
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        let number = &line[start..i];
+        numbers.push(
+            number
+                .parse::<T>()
+                .map_err(|_| ExtractIntsError::OutOfRange {
+                    number: number.to_string(),
+                    line: line.to_string(),
+                })?,
+        );
+    }
+    Ok(numbers)
+}
+
+/// Pulls exactly `N` integers out of a line of text (see [`ints`]).
+///
+/// # Errors
+/// Returns [`ExtractIntsError::WrongCount`] if the line holds more or fewer than `N`
+/// integers, or [`ExtractIntsError::OutOfRange`] if one does not fit in `T`.
+///
+/// # Example
+/// ```rust
+/// use aoc_2025::utils::parsing::ints_array;
+/// let [x, y, z] = ints_array::<i64, 3>("162,817,-812").unwrap();
+/// assert_eq!((x, y, z), (162, 817, -812));
+/// assert!(ints_array::<i64, 2>("162,817,-812").is_err());
+/// ```
+pub fn ints_array<T: ScanInt, const N: usize>(line: &str) -> Result<[T; N], ExtractIntsError> {
+    let numbers = ints::<T>(line)?;
+    numbers
+        .try_into()
+        .map_err(|numbers: Vec<T>| ExtractIntsError::WrongCount {
+            expected: N,
+            found: numbers.len(),
+            line: line.to_string(),
+        })
+}
+
+/// Pulls exactly two integers out of a line of text, e.g. the ends of a range like
+/// `"3-5"` or a coordinate like `"7,1"` (see [`ints_array`]).
+pub fn ints_pair<T: ScanInt>(line: &str) -> Result<(T, T), ExtractIntsError> {
+    let [a, b] = ints_array(line)?;
+    Ok((a, b))
+}
+
+/// Pulls exactly three integers out of a line of text (see [`ints_array`]).
+pub fn ints_triple<T: ScanInt>(line: &str) -> Result<(T, T, T), ExtractIntsError> {
+    let [a, b, c] = ints_array(line)?;
+    Ok((a, b, c))
+}
+
+/// A value which can fill a `{}` placeholder in a [`scan!`](crate::scan) pattern.
+///
+/// Implemented for the built-in integer and float types, `char` and `String` (all of
+/// which ignore surrounding whitespace), and for `Vec<T>` of any of those, which reads a
+/// list separated by whitespace and/or commas.
+pub trait FromScan: Sized {
+    /// Reads a value from the text matched by a placeholder.
+    ///
+    /// # Errors
+    /// Returns the part of `field` at fault (which must be a slice of `field`) and what is
+    /// wrong with it.
+    fn from_scan(field: &str) -> Result<Self, (&str, String)>;
+}
+
+macro_rules! impl_from_scan {
+    ($($t:ty),*) => {
+        $(impl FromScan for $t {
+            fn from_scan(field: &str) -> Result<Self, (&str, String)> {
+                let field = field.trim();
+                field
+                    .parse()
+                    .map_err(|_| (field, format!("expected a {}", stringify!($t))))
+            }
+        })*
+    };
+}
+
+impl_from_scan!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64, char);
+
+impl FromScan for String {
+    fn from_scan(field: &str) -> Result<Self, (&str, String)> {
+        Ok(field.trim().to_string())
+    }
+}
+
+impl<T: FromScan> FromScan for Vec<T> {
+    fn from_scan(field: &str) -> Result<Self, (&str, String)> {
+        field
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|item| !item.is_empty())
+            .map(T::from_scan)
+            .collect()
+    }
+}
+
+/// Why a line did not match a [`scan!`](crate::scan) pattern.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScanError {
+    /// Some literal text of the pattern was missing, from byte `at` of the line onwards.
+    Mismatch {
+        expected: String,
+        at: usize,
+        line: String,
+    },
+    /// The text matched by a placeholder (counting from 1), at bytes `span` of the line,
+    /// could not be read as the type asked for.
+    BadField {
+        placeholder: usize,
+        span: Range<usize>,
+        message: String,
+        line: String,
+    },
+}
+
+impl fmt::Display for ScanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScanError::Mismatch { expected, at, line } => {
+                write!(f, "expected {:?} after {:?}", expected, &line[..*at])
+            }
+            ScanError::BadField {
+                placeholder,
+                span,
+                message,
+                line,
+            } => write!(
+                f,
+                "placeholder {}: {}, found {:?} in {:?}",
+                placeholder,
+                message,
+                &line[span.clone()],
+                line
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ScanError {}
+
+impl ScanError {
+    /// Turns this into a [`ParseError`] pointing at the problem within line `line_no` of
+    /// the input, whose text is `line`.
+    pub fn at_line(self, line_no: usize, line: &str) -> ParseError {
+        match self {
+            ScanError::Mismatch { expected, at, .. } => ParseError::new(
+                line_no,
+                line,
+                char_offset(line, at)..char_offset(line, line.len()).max(char_offset(line, at) + 1),
+                format!("expected `{}`", expected),
+            ),
+            ScanError::BadField {
+                placeholder,
+                span,
+                message,
+                ..
+            } => ParseError::new(
+                line_no,
+                line,
+                char_offset(line, span.start)..char_offset(line, span.end),
+                format!("placeholder {}: {}", placeholder, message),
+            ),
+        }
+    }
+}
+
+/// Matches `line` against `pattern`, returning the byte range of the line matched by each
+/// `{}` placeholder.
+///
+/// Each placeholder runs up to the first occurrence of the literal text after it, except
+/// the last, which runs up to whatever literal text ends the pattern. This is what
+/// [`scan!`](crate::scan) uses to split a line up; call that instead.
+///
+/// # Errors
+/// Returns [`ScanError::Mismatch`] if the literal text of the pattern is not in the line.
+///
+/// # Panics
+/// Panics if `pattern` does not have exactly `placeholders` placeholders, or has two
+/// placeholders with nothing between them.
+pub fn scan_fields(
+    line: &str,
+    pattern: &str,
+    placeholders: usize,
+) -> Result<Vec<Range<usize>>, ScanError> {
+    let literals: Vec<&str> = pattern.split("{}").collect();
+    assert_eq!(
+        literals.len() - 1,
+        placeholders,
+        "pattern {:?} should have {} placeholders",
+        pattern,
+        placeholders
+    );
+    let mismatch = |expected: &str, at: usize| ScanError::Mismatch {
+        expected: expected.to_string(),
+        at,
+        line: line.to_string(),
+    };
+
+    if !line.starts_with(literals[0]) {
+        return Err(mismatch(literals[0], 0));
+    }
+    let mut pos = literals[0].len();
+    let mut fields = Vec::with_capacity(placeholders);
+    for (i, literal) in literals.iter().enumerate().skip(1) {
+        if i == placeholders {
+            match line[pos..].strip_suffix(literal) {
+                Some(field) => fields.push(pos..pos + field.len()),
+                None => return Err(mismatch(literal, pos)),
+            }
+        } else {
+            assert!(
+                !literal.is_empty(),
+                "pattern {:?} has two placeholders with nothing between them",
+                pattern
+            );
+            match line[pos..].find(literal) {
+                Some(len) => {
+                    fields.push(pos..pos + len);
+                    pos += len + literal.len();
+                }
+                None => return Err(mismatch(literal, pos)),
+            }
+        }
+    }
+    Ok(fields)
+}
+
+/// Reads placeholder number `placeholder` (counting from 1), at bytes `span` of `line`, as
+/// a `T`. Used by [`scan!`](crate::scan).
+///
+/// # Errors
+/// Returns [`ScanError::BadField`] if the text cannot be read as a `T`.
+pub fn scan_field<T: FromScan>(
+    line: &str,
+    placeholder: usize,
+    span: Range<usize>,
+) -> Result<T, ScanError> {
+    T::from_scan(&line[span.clone()]).map_err(|(part, message)| {
+        let start = part.as_ptr() as usize - line.as_ptr() as usize;
+        ScanError::BadField {
+            placeholder,
+            span: start..start + part.len(),
+            message,
+            line: line.to_string(),
+        }
+    })
+}
+
+/// Parses a line of text against a pattern of literal text and `{}` placeholders, giving
+/// a tuple with one value per placeholder, of the types listed after the pattern.
+///
+/// Each placeholder runs up to the first occurrence of the literal text after it (the
+/// last runs to the end of the line, less any literal text ending the pattern), so two
+/// placeholders must have something between them. Any [`FromScan`] type can be used,
+/// including `Vec<T>` for a list separated by whitespace and/or commas.
+///
+/// Evaluates to a `Result<(..), ScanError>`; use [`ScanError::at_line`] to point at the
+/// problem within the input.
+///
+/// # Panics
+/// Panics if the pattern has a different number of placeholders to the types given.
+///
+/// # Example
+/// ```rust
+/// use aoc_2025::scan;
+/// let (width, height, counts) = scan!("4x4: 0 0 2", "{}x{}: {}", u32, u32, Vec<u32>).unwrap();
+/// assert_eq!((width, height, counts), (4, 4, vec![0, 0, 2]));
+///
+/// let (device, outputs) = scan!("aaa: bbb ccc", "{}: {}", String, Vec<String>).unwrap();
+/// assert_eq!((device.as_str(), outputs), ("aaa", vec!["bbb".to_string(), "ccc".to_string()]));
+///
+/// assert!(scan!("3-x", "{}-{}", u64, u64).is_err());
+/// ```
+#[macro_export]
+macro_rules! scan {
+    ($line:expr, $pattern:expr, $($t:ty),+ $(,)?) => {{
+        fn scan(
+            line: &str,
+            pattern: &str,
+        ) -> Result<($($t,)+), $crate::utils::parsing::ScanError> {
+            let placeholders = [$(stringify!($t)),+].len();
+            let mut fields = $crate::utils::parsing::scan_fields(line, pattern, placeholders)?
+                .into_iter()
+                .enumerate();
+            Ok(($({
+                let (i, span) = fields.next().unwrap();
+                $crate::utils::parsing::scan_field::<$t>(line, i + 1, span)?
+            },)+))
+        }
+        scan($line, $pattern)
+    }};
+}
+
+/// A problem with the puzzle input, pointing at where in the input it is.
+///
+/// Displays as the message followed by the offending line, with carets under the
+/// problem:
+///
+/// ```text
+/// line 3, column 2: expected a distance after the direction
+///   |
+/// 3 | Lx8
+///   |  ^^
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The line the problem is on, counting from 1.
+    pub line: usize,
+    /// Which characters of the line are at fault, counting from 0.
+    pub columns: Range<usize>,
+    /// The whole text of the offending line.
+    pub text: String,
+    /// What is wrong.
+    pub message: String,
+}
+
+impl ParseError {
+    /// A problem with the given characters (counting from 0) of line `line_no` (counting
+    /// from 1), whose text is `text`.
+    pub fn new(
+        line_no: usize,
+        text: &str,
+        columns: Range<usize>,
+        message: impl Into<String>,
+    ) -> Self {
+        ParseError {
+            line: line_no,
+            columns,
+            text: text.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// A problem with the whole of line `line_no`.
+    pub fn whole_line(line_no: usize, text: &str, message: impl Into<String>) -> Self {
+        ParseError::new(line_no, text, 0..text.chars().count(), message)
+    }
+
+    /// A problem with `part`, which should be a slice of `text` (as given by `split` and
+    /// friends). If it is not, the first occurrence of `part` in `text` is blamed instead,
+    /// or the start of the line if `part` does not appear in `text` at all.
+    pub fn at_substr(line_no: usize, text: &str, part: &str, message: impl Into<String>) -> Self {
+        let offset = (part.as_ptr() as usize).wrapping_sub(text.as_ptr() as usize);
+        let bytes = if offset <= text.len() && offset + part.len() <= text.len() {
+            offset..offset + part.len()
+        } else {
+            text.find(part)
+                .map_or(0..0, |start| start..start + part.len())
+        };
+        ParseError::new(
+            line_no,
+            text,
+            char_offset(text, bytes.start)..char_offset(text, bytes.end),
+            message,
+        )
+    }
+
+    /// Moves the error down by `lines` lines, for when a block of the input was parsed on
+    /// its own and the line numbers need to refer to the whole input.
+    pub fn offset_lines(mut self, lines: usize) -> Self {
+        self.line += lines;
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let gutter = " ".repeat(self.line.to_string().len());
+        let carets = "^".repeat(self.columns.len().max(1));
+        writeln!(
+            f,
+            "line {}, column {}: {}",
+            self.line,
+            self.columns.start + 1,
+            self.message
+        )?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", self.line, self.text)?;
+        write!(
+            f,
+            "{} | {}{}",
+            gutter,
+            " ".repeat(self.columns.start),
+            carets
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// The lines of some text, each with its line number counting from 1, ready for
+/// reporting a [`ParseError`].
+///
+/// # Example
+/// ```rust
+/// use aoc_2025::utils::parsing::numbered_lines;
+/// let lines: Vec<(usize, &str)> = numbered_lines("L68\nR48").collect();
+/// assert_eq!(lines, [(1, "L68"), (2, "R48")]);
+/// ```
+pub fn numbered_lines(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    lines(text).enumerate().map(|(i, line)| (i + 1, line))
+}
+
+/// A block of the puzzle input, separated from the rest by blank lines.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Section<'a> {
+    /// The text before the colon, if the first line of the section is just a label
+    /// like `"0:"`.
+    pub label: Option<&'a str>,
+    /// The line the section starts on (its label, if it has one), counting from 1.
+    pub start_line: usize,
+    /// The lines of the section after any label, without line endings or trailing
+    /// whitespace.
+    pub lines: Vec<&'a str>,
+}
+
+impl<'a> Section<'a> {
+    /// The line the first of [`lines`](Section::lines) is on, counting from 1.
+    pub fn body_start_line(&self) -> usize {
+        self.start_line + usize::from(self.label.is_some())
+    }
+
+    /// The lines of the section after any label, with their line numbers within the whole
+    /// input (see [`numbered_lines`]).
+    pub fn numbered_lines(&self) -> impl Iterator<Item = (usize, &'a str)> + '_ {
+        let first = self.body_start_line();
+        self.lines
+            .iter()
+            .enumerate()
+            .map(move |(i, line)| (first + i, *line))
+    }
+
+    /// The lines of the section after any label, joined by `\n` with no final newline.
+    pub fn text(&self) -> String {
+        self.lines.join("\n")
+    }
+}
+
+/// Splits the puzzle input into sections separated by blank lines.
+///
+/// Lines may end in `\n` or `\r\n`, and have trailing whitespace, which is dropped. Lines
+/// holding only whitespace count as blank, and any number of blank lines (including at
+/// the start or end of the input) separate sections. If the first line of a section is
+/// a label ending in a colon, like `"0:"`, it is split off into [`Section::label`].
+///
+/// # Example
+/// ```rust
+/// use aoc_2025::utils::parsing::sections;
+/// let input = "3-5\r\n10-14\r\n  \r\n1\r\n\r\n0:\r\n##\r\n";
+/// let sections: Vec<_> = sections(input).collect();
+/// assert_eq!(sections.len(), 3);
+/// assert_eq!(sections[0].lines, ["3-5", "10-14"]);
+/// assert_eq!((sections[1].start_line, sections[1].text()), (4, "1".to_string()));
+/// assert_eq!((sections[2].label, sections[2].body_start_line()), (Some("0"), 7));
+/// ```
+pub fn sections(text: &str) -> impl Iterator<Item = Section<'_>> {
+    let mut lines = numbered_lines(text)
+        .map(|(line_no, line)| (line_no, line.trim_end()))
+        .peekable();
+    std::iter::from_fn(move || {
+        while lines.next_if(|(_, line)| line.is_empty()).is_some() {}
+        let (start_line, first) = lines.next()?;
+        let label = first
+            .strip_suffix(':')
+            .map(str::trim)
+            .filter(|label| !label.is_empty());
+        let mut body = Vec::new();
+        if label.is_none() {
+            body.push(first);
+        }
+        while let Some((_, line)) = lines.next_if(|(_, line)| !line.is_empty()) {
+            body.push(line);
+        }
+        Some(Section {
+            label,
+            start_line,
+            lines: body,
+        })
+    })
+}
+
+/// A labelled block of ASCII art, like a shape drawn in `#`s and `.`s under a `"0:"`
+/// header (see [`art_blocks`]).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArtBlock<'a> {
+    /// The text of the header before the colon.
+    pub label: &'a str,
+    /// The line the header is on, counting from 1.
+    pub line: usize,
+    /// The filled cells, moved up and left so that the top-most cell is in row 0 and the
+    /// left-most cell is in column 0.
+    pub cells: Vec<Pos>,
+}
+
+/// The result of [`art_blocks`]: the blocks of ASCII art, and every other line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArtBlocks<'a> {
+    pub blocks: Vec<ArtBlock<'a>>,
+    /// The non-blank lines which are not part of a block, with their line numbers.
+    pub other_lines: Vec<(usize, &'a str)>,
+}
+
+/// Picks out the labelled blocks of ASCII art in the puzzle input: sections (see
+/// [`sections`]) whose first line is a label like `"0:"` and whose next line is drawn
+/// only in `filled` and `empty` characters.
+///
+/// # Errors
+/// Returns a [`ParseError`] if a later line of a block has any other character.
+///
+/// # Example
+/// ```rust
+/// use aoc_2025::utils::parsing::art_blocks;
+/// let input = "0:\n..#\n.##\n\n1:\n#\n\n4x4: 0 2\n12x5: 1 0";
+/// let art = art_blocks(input, '#', '.').unwrap();
+/// assert_eq!((art.blocks[0].label, art.blocks[0].line), ("0", 1));
+/// assert_eq!(art.blocks[0].cells, [(0, 1), (1, 0), (1, 1)]);
+/// assert_eq!(art.blocks[1].cells, [(0, 0)]);
+/// assert_eq!(art.other_lines, [(8, "4x4: 0 2"), (9, "12x5: 1 0")]);
+/// ```
+pub fn art_blocks(input: &str, filled: char, empty: char) -> Result<ArtBlocks<'_>, ParseError> {
+    let all_lines: Vec<&str> = lines(input).collect();
+    let is_art = |line: &str| line.chars().all(|c| c == filled || c == empty);
+    let mut art = ArtBlocks {
+        blocks: Vec::new(),
+        other_lines: Vec::new(),
+    };
+
+    for section in sections(input) {
+        let label = match section.label {
+            Some(label) if section.lines.first().is_some_and(|line| is_art(line)) => label,
+            Some(_) => {
+                let header = all_lines[section.start_line - 1].trim_end();
+                art.other_lines.push((section.start_line, header));
+                art.other_lines.extend(section.numbered_lines());
+                continue;
+            }
+            None => {
+                art.other_lines.extend(section.numbered_lines());
+                continue;
+            }
+        };
+
+        let mut cells = Vec::new();
+        for (row, (line_no, line)) in section.numbered_lines().enumerate() {
+            for (col, c) in line.chars().enumerate() {
+                if c == filled {
+                    cells.push((row, col));
+                } else if c != empty {
+                    return Err(ParseError::new(
+                        line_no,
+                        line,
+                        col..col + 1,
+                        format!("expected {:?} or {:?}, not {:?}", filled, empty, c),
+                    ));
+                }
+            }
+        }
+        let top = cells.iter().map(|&(row, _)| row).min().unwrap_or(0);
+        let left = cells.iter().map(|&(_, col)| col).min().unwrap_or(0);
+        art.blocks.push(ArtBlock {
+            label,
+            line: section.start_line,
+            cells: cells
+                .into_iter()
+                .map(|(row, col)| (row - top, col - left))
+                .collect(),
+        });
+    }
+    Ok(art)
+}
+
+/// Which way to read across the columns of a [`ColumnGroup`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReadDirection {
+    LeftToRight,
+    RightToLeft,
+}
+
+/// A run of columns in a block of text laid out in columns, with blank columns either
+/// side (see [`column_groups`]).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnGroup {
+    /// The column of the block the group starts at, counting from 0.
+    pub start_column: usize,
+    /// The rows of the group, padded with spaces to the same width.
+    rows: Vec<Vec<char>>,
+}
+
+impl ColumnGroup {
+    /// How many columns wide the group is.
+    pub fn width(&self) -> usize {
+        self.rows.first().map_or(0, Vec::len)
+    }
+
+    /// The group read row by row, top to bottom. Each row is padded with spaces to the
+    /// width of the group.
+    pub fn rows(&self) -> Vec<String> {
+        self.rows.iter().map(|row| row.iter().collect()).collect()
+    }
+
+    /// The group read column by column, in the given direction. Each column is read top
+    /// to bottom, with spaces where a row has nothing in that column.
+    pub fn columns(&self, direction: ReadDirection) -> Vec<String> {
+        let column = |col: usize| self.rows.iter().map(|row| row[col]).collect();
+        match direction {
+            ReadDirection::LeftToRight => (0..self.width()).map(column).collect(),
+            ReadDirection::RightToLeft => (0..self.width()).rev().map(column).collect(),
+        }
+    }
+}
+
+/// Splits a block of text laid out in columns into groups of columns, separated by
+/// columns which are blank all the way down.
+///
+/// Shorter lines are treated as padded with spaces to the length of the longest, and
+/// columns are counted in characters.
+///
+/// # Example
+/// ```rust
+/// use aoc_2025::utils::parsing::{column_groups, ReadDirection};
+/// let groups = column_groups(["123 328", " 45 64", "  6 98"]);
+/// assert_eq!(groups.len(), 2);
+/// assert_eq!(groups[0].rows(), ["123", " 45", "  6"]);
+/// assert_eq!(groups[0].columns(ReadDirection::LeftToRight), ["1  ", "24 ", "356"]);
+/// assert_eq!((groups[1].start_column, groups[1].width()), (4, 3));
+/// assert_eq!(groups[1].columns(ReadDirection::RightToLeft), ["8  ", "248", "369"]);
+/// ```
+pub fn column_groups<'a>(lines: impl IntoIterator<Item = &'a str>) -> Vec<ColumnGroup> {
+    let lines: Vec<Vec<char>> = lines
+        .into_iter()
+        .map(|line| line.chars().collect())
         .collect();
+    let width = lines.iter().map(Vec::len).max().unwrap_or(0);
+    let at = |line: &Vec<char>, col: usize| line.get(col).copied().unwrap_or(' ');
+    let blank = |col: usize| lines.iter().all(|line| at(line, col).is_whitespace());
+
+    let mut groups = Vec::new();
+    let mut col = 0;
+    while col < width {
+        if blank(col) {
+            col += 1;
+            continue;
+        }
+        let start_column = col;
+        while col < width && !blank(col) {
+            col += 1;
+        }
+        groups.push(ColumnGroup {
+            start_column,
+            rows: lines
+                .iter()
+                .map(|line| (start_column..col).map(|c| at(line, c)).collect())
+                .collect(),
+        });
+    }
+    groups
+}
+
+/// The kinds of bracket which [`bracket_groups`] understands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Bracket {
+    /// `[...]`
+    Square,
+    /// `(...)`
+    Round,
+    /// `{...}`
+    Curly,
+    /// `<...>`
+    Angle,
+}
+
+impl Bracket {
+    /// The bracket which `c` opens, if it opens one.
+    pub fn opened_by(c: char) -> Option<Bracket> {
+        match c {
+            '[' => Some(Bracket::Square),
+            '(' => Some(Bracket::Round),
+            '{' => Some(Bracket::Curly),
+            '<' => Some(Bracket::Angle),
+            _ => None,
+        }
+    }
+
+    /// The character which opens this kind of bracket.
+    pub fn open(self) -> char {
+        match self {
+            Bracket::Square => '[',
+            Bracket::Round => '(',
+            Bracket::Curly => '{',
+            Bracket::Angle => '<',
+        }
+    }
+
+    /// The character which closes this kind of bracket.
+    pub fn close(self) -> char {
+        match self {
+            Bracket::Square => ']',
+            Bracket::Round => ')',
+            Bracket::Curly => '}',
+            Bracket::Angle => '>',
+        }
+    }
+}
+
+/// A bracketed group within a line, like `[.##.]`, `(0,2)` or `{3,5,4}`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BracketGroup<'a> {
+    /// Which kind of bracket the group is in.
+    pub bracket: Bracket,
+    /// Everything between the brackets.
+    pub contents: &'a str,
+    /// The contents split on commas, with surrounding whitespace trimmed. Empty for `()`.
+    pub items: Vec<&'a str>,
+    line: &'a str,
+}
+
+impl BracketGroup<'_> {
+    /// Reads each of the group's [`items`](BracketGroup::items) as a `T`.
+    ///
+    /// # Errors
+    /// Returns [`BracketError::BadItem`] if an item cannot be read as a `T`.
+    pub fn parse_items<T: FromScan>(&self) -> Result<Vec<T>, BracketError> {
+        self.items
+            .iter()
+            .map(|item| {
+                T::from_scan(item).map_err(|(part, message)| {
+                    let start = part.as_ptr() as usize - self.line.as_ptr() as usize;
+                    BracketError::BadItem {
+                        span: start..start + part.len(),
+                        message,
+                        line: self.line.to_string(),
+                    }
+                })
+            })
+            .collect()
+    }
+}
+
+/// Why a line could not be split into bracket groups.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BracketError {
+    /// The bracket opened at byte `at` of the line was never closed.
+    Unclosed { open: char, at: usize, line: String },
+    /// The character at byte `at` of the line was not expected there: a closing bracket
+    /// which does not match, a bracket within another group, or text between groups.
+    Unexpected {
+        found: char,
+        at: usize,
+        line: String,
+    },
+    /// An item at bytes `span` of the line could not be read as the type asked for.
+    BadItem {
+        span: Range<usize>,
+        message: String,
+        line: String,
+    },
+}
+
+impl fmt::Display for BracketError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BracketError::Unclosed { open, at, line } => {
+                write!(f, "{:?} at byte {} is never closed in {:?}", open, at, line)
+            }
+            BracketError::Unexpected { found, at, line } => {
+                write!(f, "unexpected {:?} at byte {} in {:?}", found, at, line)
+            }
+            BracketError::BadItem {
+                span,
+                message,
+                line,
+            } => write!(
+                f,
+                "{}, found {:?} in {:?}",
+                message,
+                &line[span.clone()],
+                line
+            ),
+        }
+    }
+}
+
+impl std::error::Error for BracketError {}
 
-    iterator
+impl BracketError {
+    /// Turns this into a [`ParseError`] pointing at the problem within line `line_no` of
+    /// the input, whose text is `line`.
+    pub fn at_line(self, line_no: usize, line: &str) -> ParseError {
+        match self {
+            BracketError::Unclosed { open, at, .. } => ParseError::new(
+                line_no,
+                line,
+                char_offset(line, at)..char_offset(line, at) + 1,
+                format!("{:?} is never closed", open),
+            ),
+            BracketError::Unexpected { found, at, .. } => ParseError::new(
+                line_no,
+                line,
+                char_offset(line, at)..char_offset(line, at) + 1,
+                format!("unexpected {:?}", found),
+            ),
+            BracketError::BadItem { span, message, .. } => ParseError::new(
+                line_no,
+                line,
+                char_offset(line, span.start)..char_offset(line, span.end),
+                message,
+            ),
+        }
+    }
+}
+
+/// Splits a line into the bracketed groups it is made of, like the `[.##.]`, `(0,2)`
+/// and `{3,5,4}` in `"[.##.] (3) (1,3) (0,2) {3,5,4,7}"`.
+///
+/// Groups may be separated by whitespace but nothing else, and cannot be nested.
+///
+/// # Errors
+/// Returns [`BracketError::Unclosed`] if a group is not closed, or
+/// [`BracketError::Unexpected`] for anything else out of place.
+///
+/// # Example
+/// ```rust
+/// use aoc_2025::utils::parsing::{bracket_groups, Bracket};
+/// let groups = bracket_groups("[.##.] (3) (1,3) {3,5,4,7}").unwrap();
+/// assert_eq!((groups[0].bracket, groups[0].contents), (Bracket::Square, ".##."));
+/// assert_eq!(groups[2].items, ["1", "3"]);
+/// assert_eq!(groups[3].parse_items::<u32>().unwrap(), [3, 5, 4, 7]);
+/// assert!(bracket_groups("[.##.] (3").is_err());
+/// ```
+pub fn bracket_groups(line: &str) -> Result<Vec<BracketGroup<'_>>, BracketError> {
+    let mut groups = Vec::new();
+    let mut chars = line.char_indices();
+    while let Some((at, c)) = chars.next() {
+        if c.is_whitespace() {
+            continue;
+        }
+        let unexpected = |found: char, at: usize| BracketError::Unexpected {
+            found,
+            at,
+            line: line.to_string(),
+        };
+        let bracket = Bracket::opened_by(c).ok_or_else(|| unexpected(c, at))?;
+        let start = at + c.len_utf8();
+        let end = loop {
+            match chars.next() {
+                Some((end, c)) if c == bracket.close() => break end,
+                Some((inner, c)) if Bracket::opened_by(c).is_some() || is_closing(c) => {
+                    return Err(unexpected(c, inner))
+                }
+                Some(_) => {}
+                None => {
+                    return Err(BracketError::Unclosed {
+                        open: c,
+                        at,
+                        line: line.to_string(),
+                    })
+                }
+            }
+        };
+        let contents = &line[start..end];
+        groups.push(BracketGroup {
+            bracket,
+            contents,
+            items: contents
+                .split(',')
+                .map(str::trim)
+                .filter(|item| !item.is_empty())
+                .collect(),
+            line,
+        });
+    }
+    Ok(groups)
+}
+
+/// Whether `c` closes any kind of bracket.
+fn is_closing(c: char) -> bool {
+    matches!(c, ']' | ')' | '}' | '>')
+}
+
+/// Converts a byte offset within `text` to a character offset.
+fn char_offset(text: &str, byte_offset: usize) -> usize {
+    text[..byte_offset].chars().count()
 }
 
 #[cfg(test)]
@@ -62,4 +1102,183 @@ mod tests {
             ["This should be ", " split in two!"]
         )
     }
+
+    #[test]
+    fn test_borrowing_splits_match_owned() {
+        let text = "1-2,3-4\r\n\n5-6";
+        assert_eq!(
+            lines(text).collect::<Vec<_>>(),
+            split_lines(text.to_string())
+        );
+        assert_eq!(
+            split_by(text, ",").collect::<Vec<_>>(),
+            split_string_by_specified_char(text.to_string(), ",")
+        );
+        assert_eq!(lines(text).collect::<Vec<_>>(), ["1-2,3-4", "", "5-6"]);
+    }
+
+    #[test]
+    fn test_scan() {
+        assert_eq!(scan!("3-5", "{}-{}", u64, u64), Ok((3, 5)));
+        assert_eq!(scan!("R49", "R{}", i32), Ok((49,)));
+        assert_eq!(scan!("12,7,3", "{}", Vec<u8>), Ok((vec![12, 7, 3],)));
+        assert_eq!(
+            scan!("p=<1,-2> v=[3]", "p=<{},{}> v=[{}]", i8, i8, char),
+            Ok((1, -2, '3'))
+        );
+
+        let err = scan!("4x4 0 0", "{}x{}: {}", u32, u32, Vec<u32>).unwrap_err();
+        assert_eq!(
+            err.at_line(2, "4x4 0 0").columns,
+            2..7,
+            "missing literal should point at the rest of the line"
+        );
+        let err = scan!("4x4: 0 z 2", "{}x{}: {}", u32, u32, Vec<u32>).unwrap_err();
+        let err = err.at_line(2, "4x4: 0 z 2");
+        assert_eq!(
+            (err.columns, err.message.as_str()),
+            (7..8, "placeholder 3: expected a u32")
+        );
+    }
+
+    #[test]
+    fn test_sections_tolerate_crlf_and_whitespace() {
+        let unix = "3-5\n10-14\n\n1\n5\n";
+        let windows = "\r\n3-5 \r\n10-14\r\n \t\r\n\r\n1\r\n5\t\r\n\r\n";
+        let lines = |text| -> Vec<Vec<&str>> { sections(text).map(|s| s.lines).collect() };
+        assert_eq!(lines(unix), [vec!["3-5", "10-14"], vec!["1", "5"]]);
+        assert_eq!(lines(windows), lines(unix));
+
+        let second = sections(windows).nth(1).unwrap();
+        assert_eq!(second.label, None);
+        assert_eq!(
+            second.numbered_lines().collect::<Vec<_>>(),
+            [(6, "1"), (7, "5")]
+        );
+        assert_eq!(sections("").count(), 0);
+    }
+
+    #[test]
+    fn test_column_groups_pad_ragged_lines() {
+        let groups = column_groups(["64 ", "23  12", "314", "", "  +"]);
+        let starts: Vec<usize> = groups.iter().map(|group| group.start_column).collect();
+        assert_eq!(starts, [0, 4]);
+        assert_eq!(groups[0].rows(), ["64 ", "23 ", "314", "   ", "  +"]);
+        assert_eq!(
+            groups[0].columns(ReadDirection::RightToLeft),
+            ["  4 +", "431  ", "623  "]
+        );
+        assert_eq!(
+            groups[1].columns(ReadDirection::LeftToRight),
+            [" 1   ", " 2   "]
+        );
+        assert!(column_groups(["   ", ""]).is_empty());
+    }
+
+    #[test]
+    fn test_bracket_groups() {
+        let line = "[.##.] (3) (1,3) () {3, 5,4,7}";
+        let groups = bracket_groups(line).unwrap();
+        let brackets: Vec<Bracket> = groups.iter().map(|group| group.bracket).collect();
+        use Bracket::*;
+        assert_eq!(brackets, [Square, Round, Round, Round, Curly]);
+        assert!(groups[3].items.is_empty());
+        assert_eq!(groups[4].items, ["3", "5", "4", "7"]);
+
+        let columns = |line| bracket_groups(line).unwrap_err().at_line(1, line).columns;
+        assert_eq!(columns("[.#] (1,2"), 5..6);
+        assert_eq!(columns("[.#) (1,2)"), 3..4);
+        assert_eq!(columns("[.#] x (1)"), 5..6);
+        assert_eq!(columns("(1,(2))"), 3..4);
+
+        let err = bracket_groups("{3,x,4}").unwrap()[0]
+            .parse_items::<u32>()
+            .unwrap_err()
+            .at_line(1, "{3,x,4}");
+        assert_eq!(
+            (err.columns, err.message.as_str()),
+            (3..4, "expected a u32")
+        );
+    }
+
+    #[test]
+    fn test_art_blocks() {
+        let input = "Notes:\nnot art\n\n7:\r\n....\r\n.#..\r\n..#.\r\n\r\n8:\n...\n";
+        let art = art_blocks(input, '#', '.').unwrap();
+        assert_eq!(art.other_lines, [(1, "Notes:"), (2, "not art")]);
+        assert_eq!(art.blocks.len(), 2);
+        assert_eq!(art.blocks[0].cells, [(0, 0), (1, 1)]);
+        assert_eq!((art.blocks[1].label, art.blocks[1].line), ("8", 9));
+        assert!(art.blocks[1].cells.is_empty());
+
+        let err = art_blocks("0:\n##\n#x", '#', '.').unwrap_err();
+        assert_eq!((err.line, err.columns), (3, 1..2));
+    }
+
+    #[test]
+    fn test_ints_minus_sign_vs_dash() {
+        assert_eq!(
+            ints::<i32>("-1 2--3 4-5 x=-6").unwrap(),
+            [-1, 2, -3, 4, 5, -6]
+        );
+        assert_eq!(ints::<u32>("-1 2--3 4-5 x=-6").unwrap(), [1, 2, 3, 4, 5, 6]);
+        assert!(ints::<u8>("no numbers here").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_ints_errors() {
+        assert_eq!(
+            ints::<u8>("1 256"),
+            Err(ExtractIntsError::OutOfRange {
+                number: "256".to_string(),
+                line: "1 256".to_string()
+            })
+        );
+        assert!(ints::<u8>("-1").is_ok());
+        assert!(ints::<i8>("-129").is_err());
+        assert_eq!(
+            ints_pair::<u32>("1-2-3"),
+            Err(ExtractIntsError::WrongCount {
+                expected: 2,
+                found: 3,
+                line: "1-2-3".to_string()
+            })
+        );
+        assert_eq!(ints_triple::<i64>("1,-2,3").unwrap(), (1, -2, 3));
+    }
+
+    #[test]
+    fn test_parse_error_display() {
+        let line = "R12 Lx8 R3";
+        let err = ParseError::at_substr(12, line, &line[4..7], "expected a rotation");
+        assert_eq!(err.columns, 4..7);
+        assert_eq!(
+            err.to_string(),
+            "line 12, column 5: expected a rotation\n   |\n12 | R12 Lx8 R3\n   |     ^^^"
+        );
+        assert_eq!(
+            ParseError::at_substr(1, "ab ab", "ab", "oops").columns,
+            0..2,
+            "a copy of the text should fall back to the first match"
+        );
+        assert_eq!(err.offset_lines(3).line, 15);
+    }
+
+    #[test]
+    fn test_parse_error_at_missing_substr() {
+        let err = ParseError::at_substr(2, "ab", "not in the line", "oops");
+        assert_eq!(err.columns, 0..0);
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: oops\n  |\n2 | ab\n  | ^"
+        );
+    }
+
+    #[test]
+    fn test_ints_error_at_line() {
+        let err = ints_pair::<u8>("1-300").unwrap_err().at_line(4, "1-300");
+        assert_eq!((err.line, err.columns), (4, 2..5));
+        let err = ints_pair::<u8>("1-3-5").unwrap_err().at_line(4, "1-3-5");
+        assert_eq!(err.columns, 0..5);
+    }
 }
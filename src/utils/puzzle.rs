@@ -0,0 +1,276 @@
+use regex::{Captures, Regex};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::utils::calendar::validate_puzzle;
+use crate::utils::client::AocClient;
+use crate::utils::error::AocInputError;
+use crate::utils::get_input::check_status;
+
+/// Default directory where puzzle descriptions are saved as Markdown.
+pub const DEFAULT_PUZZLE_DIR: &str = "puzzles";
+/// Default directory where example inputs extracted from puzzle descriptions are saved.
+pub const DEFAULT_FIXTURE_DIR: &str = "test-inputs";
+
+/// A code block lifted from a puzzle description, which may be a worked example input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PuzzleExample {
+    /// Which part of the puzzle (`1` or `2`) the block appeared in.
+    pub part: u8,
+    /// The position of the block within that part, counting from 1.
+    pub index: usize,
+    /// The contents of the block, with any HTML removed.
+    pub text: String,
+}
+
+/// A downloaded puzzle description page.
+#[derive(Debug, Clone)]
+pub struct PuzzlePage {
+    pub year: u32,
+    pub day: u32,
+    pub html: String,
+}
+
+impl PuzzlePage {
+    /// Converts the puzzle description (one `<article>` per unlocked part) to Markdown.
+    pub fn to_markdown(&self) -> String {
+        articles(&self.html)
+            .iter()
+            .map(|article| html_to_markdown(article))
+            .collect::<Vec<String>>()
+            .join("\n\n")
+    }
+
+    /// Every `<pre><code>` block in the puzzle description, numbered per part.
+    ///
+    /// Usually the first block of a part is its worked example, but not always, so these
+    /// are only candidates and should be checked before being used as test fixtures.
+    pub fn examples(&self) -> Vec<PuzzleExample> {
+        let code_block = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap();
+        let mut examples = Vec::new();
+        for (part, article) in articles(&self.html).iter().enumerate() {
+            for (index, caps) in code_block.captures_iter(article).enumerate() {
+                examples.push(PuzzleExample {
+                    part: part as u8 + 1,
+                    index: index + 1,
+                    text: unescape_html(&strip_tags(&caps[1])),
+                });
+            }
+        }
+        examples
+    }
+
+    /// Saves the description as `<puzzle_dir>/<year>/day_<day>.md`, and each candidate
+    /// example as `<fixture_dir>/<year>/day_<day>_part_<part>_example_<index>.txt`.
+    ///
+    /// # Returns
+    /// The paths of every file written, description first.
+    pub fn save(
+        &self,
+        puzzle_dir: &Path,
+        fixture_dir: &Path,
+    ) -> Result<Vec<PathBuf>, AocInputError> {
+        let mut written = Vec::new();
+
+        let year_dir = puzzle_dir.join(self.year.to_string());
+        fs::create_dir_all(&year_dir)?;
+        let description = year_dir.join(format!("day_{}.md", self.day));
+        fs::write(&description, self.to_markdown())?;
+        written.push(description);
+
+        let fixture_dir = fixture_dir.join(self.year.to_string());
+        fs::create_dir_all(&fixture_dir)?;
+        for example in self.examples() {
+            let path = fixture_dir.join(format!(
+                "day_{}_part_{}_example_{}.txt",
+                self.day, example.part, example.index
+            ));
+            fs::write(&path, &example.text)?;
+            written.push(path);
+        }
+
+        Ok(written)
+    }
+}
+
+/// Downloads the puzzle description page for a given year and day.
+///
+/// The session cookie is sent if there is one, since part 2 of a puzzle is only shown to
+/// users who have solved part 1. Without one, only part 1 is downloaded.
+///
+/// # Errors
+///
+/// Fails in the same scenarios as
+/// [`get_aoc_input`](crate::utils::get_input::get_aoc_input) does when fetching from the
+/// website, except that a missing session cookie is not an error.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use aoc_2025::utils::puzzle::{fetch_puzzle_page, DEFAULT_FIXTURE_DIR, DEFAULT_PUZZLE_DIR};
+/// use std::path::Path;
+///
+/// let page = fetch_puzzle_page(2025, 1).expect("Failed to fetch puzzle");
+/// page.save(Path::new(DEFAULT_PUZZLE_DIR), Path::new(DEFAULT_FIXTURE_DIR))
+///     .expect("Failed to save puzzle");
+/// ```
+pub fn fetch_puzzle_page(year: u32, day: u32) -> Result<PuzzlePage, AocInputError> {
+    fetch_puzzle_page_with(&AocClient::new(), year, day)
+}
+
+/// Like [`fetch_puzzle_page`], but using the given client instead of the default one.
+pub fn fetch_puzzle_page_with(
+    client: &AocClient,
+    year: u32,
+    day: u32,
+) -> Result<PuzzlePage, AocInputError> {
+    validate_puzzle(year, day)?;
+    client.ensure_unlocked(year, day)?;
+    let response = client.get(
+        &format!("/{year}/day/{day}", year = year, day = day),
+        client.resolve_session().ok().as_ref(),
+    )?;
+
+    check_status(&response, year, Some(day))?;
+    Ok(PuzzlePage {
+        year,
+        day,
+        html: response.text()?,
+    })
+}
+
+/// The inner HTML of every puzzle description `<article>` on the page, one per part.
+fn articles(html: &str) -> Vec<String> {
+    Regex::new(r#"(?s)<article class="day-desc">(.*?)</article>"#)
+        .unwrap()
+        .captures_iter(html)
+        .map(|caps| caps[1].to_string())
+        .collect()
+}
+
+/// Converts the small subset of HTML used in puzzle descriptions to Markdown.
+fn html_to_markdown(html: &str) -> String {
+    let code_block = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap();
+    let mut markdown = code_block
+        .replace_all(html, |caps: &Captures| {
+            format!("\n```\n{}```\n", strip_tags(&caps[1]))
+        })
+        .to_string();
+
+    let replacements = [
+        (r"(?s)<h2[^>]*>(.*?)</h2>", "\n## $1\n"),
+        (r"(?s)<code>(.*?)</code>", "`$1`"),
+        (r#"(?s)<em class="star">(.*?)</em>"#, "**$1**"),
+        (r"(?s)<em>(.*?)</em>", "**$1**"),
+        (r#"(?s)<a href="([^"]*)"[^>]*>(.*?)</a>"#, "[$2]($1)"),
+        (r"(?s)<li>(.*?)</li>", "- $1\n"),
+        (r"</?(ul|p)>", "\n"),
+    ];
+    for (pattern, replacement) in replacements {
+        markdown = Regex::new(pattern)
+            .unwrap()
+            .replace_all(&markdown, replacement)
+            .to_string();
+    }
+
+    let blank_lines = Regex::new(r"\n{3,}").unwrap();
+    unescape_html(&blank_lines.replace_all(&strip_tags(&markdown), "\n\n"))
+        .trim()
+        .to_string()
+}
+
+/// Removes any remaining HTML tags, keeping their contents.
+fn strip_tags(html: &str) -> String {
+    Regex::new(r"<[^>]+>")
+        .unwrap()
+        .replace_all(html, "")
+        .to_string()
+}
+
+/// Turns the HTML entities AoC uses back into the characters they stand for.
+fn unescape_html(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_page() -> PuzzlePage {
+        PuzzlePage {
+            year: 2025,
+            day: 1,
+            html: r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 1: Secret Entrance ---</h2><p>The dial starts at <code>50</code>. For <em>example</em>:</p>
+<pre><code>L68
+<em>R48</em>
+</code></pre>
+<p>Then:</p><pre><code>a &lt; b</code></pre>
+</article>
+<p>Your puzzle answer was <code>1234</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>See <a href="/2025/day/1/input">your input</a>.</p>
+<ul><li>One</li><li>Two</li></ul>
+<pre><code>R1000
+</code></pre>
+</article>
+</main></body></html>"#
+                .to_string(),
+        }
+    }
+
+    #[test]
+    fn test_examples() {
+        let examples = test_page().examples();
+        assert_eq!(
+            examples,
+            [
+                PuzzleExample {
+                    part: 1,
+                    index: 1,
+                    text: "L68\nR48\n".to_string()
+                },
+                PuzzleExample {
+                    part: 1,
+                    index: 2,
+                    text: "a < b".to_string()
+                },
+                PuzzleExample {
+                    part: 2,
+                    index: 1,
+                    text: "R1000\n".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_to_markdown() {
+        let markdown = test_page().to_markdown();
+        assert!(markdown.starts_with("## --- Day 1: Secret Entrance ---"));
+        assert!(markdown.contains("The dial starts at `50`. For **example**:"));
+        assert!(markdown.contains("```\nL68\nR48\n```"));
+        assert!(markdown.contains("## --- Part Two ---"));
+        assert!(markdown.contains("See [your input](/2025/day/1/input)."));
+        assert!(markdown.contains("- One\n- Two"));
+        assert!(!markdown.contains("Your puzzle answer was"));
+    }
+
+    #[test]
+    fn test_save() {
+        let root = std::env::temp_dir().join(format!("aoc-puzzle-{}", std::process::id()));
+        let written = test_page()
+            .save(&root.join("puzzles"), &root.join("fixtures"))
+            .unwrap();
+        assert_eq!(written.len(), 4);
+        assert_eq!(
+            fs::read_to_string(root.join("fixtures/2025/day_1_part_2_example_1.txt")).unwrap(),
+            "R1000\n"
+        );
+        assert!(root.join("puzzles/2025/day_1.md").exists());
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,348 @@
+use std::env;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::utils::error::AocInputError;
+
+/// Environment variable holding the session token. For a named profile, the profile name is
+/// upper-cased and appended, e.g. `AOC_SESSION_ALICE`.
+pub const SESSION_ENV_VAR: &str = "AOC_SESSION";
+/// Environment variable selecting which profile to use when none is given explicitly.
+pub const PROFILE_ENV_VAR: &str = "AOC_PROFILE";
+
+/// An Advent of Code session token, as found in the `session` cookie set by the website.
+///
+/// The token is kept without the `session=` prefix, and is redacted when debug-printed so it
+/// does not end up in logs.
+#[derive(Clone, PartialEq, Eq)]
+pub struct SessionToken(String);
+
+impl SessionToken {
+    /// Parses a session token, accepting it with or without a leading `session=` and
+    /// ignoring surrounding whitespace.
+    ///
+    /// # Errors
+    /// Returns [`AocInputError::InvalidSession`] if what is left is not a plausible token,
+    /// i.e. 96 to 128 hexadecimal characters.
+    ///
+    /// # Example
+    /// ```rust
+    /// use aoc_2025::utils::session::SessionToken;
+    /// let token = SessionToken::parse(&format!("session={}\n", "ab".repeat(64))).unwrap();
+    /// assert_eq!(token.cookie_header(), format!("session={}", "ab".repeat(64)));
+    /// ```
+    pub fn parse(raw: &str) -> Result<Self, AocInputError> {
+        let token = raw.trim();
+        let token = token.strip_prefix("session=").unwrap_or(token).trim();
+        if token.is_empty() {
+            return Err(AocInputError::InvalidSession("token is empty".to_string()));
+        }
+        if !token.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(AocInputError::InvalidSession(
+                "token should only contain hexadecimal characters".to_string(),
+            ));
+        }
+        if !(96..=128).contains(&token.len()) {
+            return Err(AocInputError::InvalidSession(format!(
+                "token should be 96 to 128 characters long, not {}",
+                token.len()
+            )));
+        }
+        Ok(SessionToken(token.to_string()))
+    }
+
+    /// The value to send as the `Cookie` header when talking to the website.
+    pub fn cookie_header(&self) -> String {
+        format!("session={}", self.0)
+    }
+}
+
+impl fmt::Debug for SessionToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SessionToken({}…)", &self.0[..6])
+    }
+}
+
+/// Finds the session token to use, trying each of these in order:
+///
+/// 1. The `AOC_SESSION` environment variable (`AOC_SESSION_<PROFILE>` for a named profile).
+///    An empty or all-whitespace value counts as unset, so `AOC_SESSION= aoc ...` skips it.
+/// 2. A project file, `cookie.txt` (`cookie.<profile>.txt`), in the working directory or
+///    any directory above it, or failing that in the root of this crate.
+/// 3. A per-user config file, `~/.config/aoc/session` (`~/.config/aoc/session.<profile>`),
+///    respecting `XDG_CONFIG_HOME` if it is set.
+///
+/// Named profiles let several people share a checkout while each using their own account.
+/// The first token found is used; if it is malformed, that is an error rather than a reason
+/// to keep looking, so a typo is never silently papered over by an older token.
+#[derive(Debug, Clone)]
+pub struct SessionResolver {
+    profile: Option<String>,
+    env_var: String,
+    project_dirs: Vec<PathBuf>,
+    config_dir: Option<PathBuf>,
+}
+
+impl Default for SessionResolver {
+    fn default() -> Self {
+        SessionResolver::new()
+    }
+}
+
+impl SessionResolver {
+    /// A resolver using the standard locations, and the profile named by `AOC_PROFILE` if
+    /// that is set.
+    pub fn new() -> Self {
+        let mut project_dirs: Vec<PathBuf> = env::current_dir()
+            .map(|dir| dir.ancestors().map(Path::to_path_buf).collect())
+            .unwrap_or_default();
+        project_dirs.push(PathBuf::from(env!("CARGO_MANIFEST_DIR")));
+        SessionResolver {
+            profile: profile_from_env(),
+            env_var: SESSION_ENV_VAR.to_string(),
+            project_dirs,
+            config_dir: user_config_dir(),
+        }
+    }
+
+    /// Uses the given named profile instead of the default one.
+    pub fn profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+
+    /// Reads the token from the given environment variable instead of `AOC_SESSION`.
+    pub fn env_var(mut self, name: &str) -> Self {
+        self.env_var = name.to_string();
+        self
+    }
+
+    /// Looks for the project file in the given directory only.
+    pub fn project_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.project_dirs = vec![dir.into()];
+        self
+    }
+
+    /// Looks for the per-user config file in the given directory.
+    pub fn config_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.config_dir = Some(dir.into());
+        self
+    }
+
+    /// Finds and validates the session token.
+    ///
+    /// # Errors
+    /// - [`AocInputError::InvalidSession`] if the profile name is unusable, or if the first
+    ///   token found is malformed.
+    /// - [`AocInputError::NoSession`] if no token could be found anywhere.
+    pub fn resolve(&self) -> Result<SessionToken, AocInputError> {
+        if let Some(profile) = &self.profile {
+            validate_profile(profile)?;
+        }
+
+        let env_var = match &self.profile {
+            Some(profile) => format!(
+                "{}_{}",
+                self.env_var,
+                profile.to_uppercase().replace('-', "_")
+            ),
+            None => self.env_var.clone(),
+        };
+        if let Some(raw) = env::var(&env_var).ok().filter(|raw| !raw.trim().is_empty()) {
+            return SessionToken::parse(&raw).map_err(|err| in_source(err, &env_var));
+        }
+
+        let project_file = match &self.profile {
+            Some(profile) => format!("cookie.{}.txt", profile),
+            None => "cookie.txt".to_string(),
+        };
+        let config_file = match &self.profile {
+            Some(profile) => format!("session.{}", profile),
+            None => "session".to_string(),
+        };
+        let candidates = self
+            .project_dirs
+            .iter()
+            .map(|dir| dir.join(&project_file))
+            .chain(self.config_dir.iter().map(|dir| dir.join(&config_file)));
+        for path in candidates {
+            if let Ok(raw) = fs::read_to_string(&path) {
+                return SessionToken::parse(&raw)
+                    .map_err(|err| in_source(err, &path.display().to_string()));
+            }
+        }
+
+        Err(AocInputError::NoSession)
+    }
+}
+
+/// Finds the session token using the standard [`SessionResolver`].
+pub fn resolve_session() -> Result<SessionToken, AocInputError> {
+    SessionResolver::new().resolve()
+}
+
+/// The per-user config directory, `~/.config/aoc`, respecting `XDG_CONFIG_HOME` if it is
+/// set. `None` if neither that nor `HOME` is set.
+pub fn user_config_dir() -> Option<PathBuf> {
+    env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .map(|dir| dir.join("aoc"))
+}
+
+/// The profile named by `AOC_PROFILE`, if it is set and not empty.
+pub fn profile_from_env() -> Option<String> {
+    env::var(PROFILE_ENV_VAR).ok().filter(|p| !p.is_empty())
+}
+
+/// Checks that a profile name is safe to use in environment variable and file names.
+///
+/// # Errors
+/// Returns [`AocInputError::InvalidSession`] if the name is empty or holds anything other
+/// than letters, digits, `-` and `_`.
+pub fn validate_profile(profile: &str) -> Result<(), AocInputError> {
+    if profile.is_empty()
+        || !profile
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err(AocInputError::InvalidSession(format!(
+            "profile name {:?} should only contain letters, digits, '-' and '_'",
+            profile
+        )));
+    }
+    Ok(())
+}
+
+/// Adds where a malformed token came from to its error message.
+fn in_source(err: AocInputError, source: &str) -> AocInputError {
+    match err {
+        AocInputError::InvalidSession(reason) => {
+            AocInputError::InvalidSession(format!("{} (from {})", reason, source))
+        }
+        other => other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOKEN: &str = "53616c7465645f5f0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef";
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-session-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_parse_normalises_prefix() {
+        let bare = SessionToken::parse(TOKEN).unwrap();
+        let prefixed = SessionToken::parse(&format!("  session={}\n", TOKEN)).unwrap();
+        assert_eq!(bare, prefixed);
+        assert_eq!(bare.cookie_header(), format!("session={}", TOKEN));
+    }
+
+    #[test]
+    fn test_parse_rejects_bad_tokens() {
+        for bad in [
+            "",
+            "session=",
+            "not-a-token",
+            &TOKEN[..40],
+            &format!("{}zz", TOKEN),
+        ] {
+            assert!(matches!(
+                SessionToken::parse(bad),
+                Err(AocInputError::InvalidSession(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_debug_is_redacted() {
+        let token = SessionToken::parse(TOKEN).unwrap();
+        assert!(!format!("{:?}", token).contains(&TOKEN[6..]));
+    }
+
+    #[test]
+    fn test_resolve_order() {
+        let project = temp_dir("project");
+        let config = temp_dir("config");
+        let resolver = SessionResolver::new()
+            .env_var("AOC_SESSION_TEST_RESOLVE_ORDER_UNSET")
+            .project_dir(&project)
+            .config_dir(&config);
+        assert!(matches!(resolver.resolve(), Err(AocInputError::NoSession)));
+
+        let config_token = TOKEN.replace('5', "6");
+        fs::write(config.join("session"), &config_token).unwrap();
+        assert_eq!(
+            resolver.resolve().unwrap(),
+            SessionToken::parse(&config_token).unwrap()
+        );
+
+        fs::write(project.join("cookie.txt"), format!("session={}\n", TOKEN)).unwrap();
+        assert_eq!(
+            resolver.resolve().unwrap(),
+            SessionToken::parse(TOKEN).unwrap()
+        );
+
+        fs::remove_dir_all(project).unwrap();
+        fs::remove_dir_all(config).unwrap();
+    }
+
+    #[test]
+    fn test_resolve_skips_blank_env_var() {
+        let project = temp_dir("blank-env");
+        fs::write(project.join("cookie.txt"), TOKEN).unwrap();
+        let resolver = SessionResolver::new()
+            .env_var("AOC_SESSION_TEST_RESOLVE_BLANK")
+            .project_dir(&project)
+            .config_dir(&project);
+
+        for blank in ["", "  \n"] {
+            env::set_var("AOC_SESSION_TEST_RESOLVE_BLANK", blank);
+            assert_eq!(
+                resolver.resolve().unwrap(),
+                SessionToken::parse(TOKEN).unwrap()
+            );
+        }
+        env::set_var("AOC_SESSION_TEST_RESOLVE_BLANK", "oops");
+        assert!(matches!(
+            resolver.resolve(),
+            Err(AocInputError::InvalidSession(_))
+        ));
+        env::remove_var("AOC_SESSION_TEST_RESOLVE_BLANK");
+        fs::remove_dir_all(project).unwrap();
+    }
+
+    #[test]
+    fn test_resolve_profile() {
+        let project = temp_dir("profile");
+        fs::write(project.join("cookie.txt"), TOKEN).unwrap();
+        fs::write(project.join("cookie.alice.txt"), "oops").unwrap();
+        let resolver = SessionResolver::new()
+            .env_var("AOC_SESSION_TEST_RESOLVE_PROFILE_UNSET")
+            .project_dir(&project)
+            .config_dir(&project);
+
+        assert!(resolver.clone().resolve().is_ok());
+        assert!(matches!(
+            resolver.clone().profile("alice").resolve(),
+            Err(AocInputError::InvalidSession(_))
+        ));
+        assert!(matches!(
+            resolver.clone().profile("bob").resolve(),
+            Err(AocInputError::NoSession)
+        ));
+        assert!(matches!(
+            resolver.profile("../bob").resolve(),
+            Err(AocInputError::InvalidSession(_))
+        ));
+        fs::remove_dir_all(project).unwrap();
+    }
+}
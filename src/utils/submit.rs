@@ -0,0 +1,219 @@
+use regex::Regex;
+use std::fmt::{self, Display};
+use std::time::Duration;
+
+use crate::utils::calendar::validate_puzzle;
+use crate::utils::client::AocClient;
+use crate::utils::error::AocInputError;
+use crate::utils::get_input::check_status;
+
+/// What the AoC website made of a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubmissionOutcome {
+    /// The answer was right, and a star has been awarded.
+    Correct,
+    /// The answer was wrong, and AoC gave no hint as to which way.
+    Incorrect,
+    /// The answer was wrong, and too high.
+    TooHigh,
+    /// The answer was wrong, and too low.
+    TooLow,
+    /// This part has already been solved, so the answer was not checked.
+    AlreadySolved,
+    /// An answer was submitted too recently, so this one was not checked. `wait` is how long
+    /// AoC says is left before another answer can be submitted.
+    RateLimited { wait: Duration },
+}
+
+impl Display for SubmissionOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SubmissionOutcome::Correct => write!(f, "That's the right answer!"),
+            SubmissionOutcome::Incorrect => write!(f, "That's not the right answer."),
+            SubmissionOutcome::TooHigh => write!(f, "That's not the right answer: too high."),
+            SubmissionOutcome::TooLow => write!(f, "That's not the right answer: too low."),
+            SubmissionOutcome::AlreadySolved => write!(f, "That part has already been solved."),
+            SubmissionOutcome::RateLimited { wait } => write!(
+                f,
+                "An answer was submitted too recently; wait {}s before trying again.",
+                wait.as_secs()
+            ),
+        }
+    }
+}
+
+/// Submits an answer for one part of a puzzle to the Advent of Code website.
+///
+/// # Parameters
+///
+/// * `year` - The year of the Advent of Code event.
+/// * `day` - The day of the Advent of Code event.
+/// * `part` - Which part of the puzzle (`1` or `2`) the answer is for.
+/// * `answer` - The answer to submit.
+///
+/// # Returns
+///
+/// The [`SubmissionOutcome`] parsed from the page AoC responds with.
+///
+/// # Errors
+///
+/// Fails in the same scenarios as
+/// [`get_aoc_input`](crate::utils::get_input::get_aoc_input) does when fetching from the
+/// website, with [`AocInputError::NoSuchPart`] (before sending anything) if `part` is not
+/// 1 or 2, or with [`AocInputError::UnexpectedResponse`] if the reply cannot be understood.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use aoc_2025::utils::submit::{submit_answer, SubmissionOutcome};
+///
+/// let outcome = submit_answer(2025, 1, 1, 1234).expect("Failed to submit answer");
+/// if outcome == SubmissionOutcome::Correct {
+///     println!("Another star!");
+/// }
+/// ```
+pub fn submit_answer(
+    year: u32,
+    day: u32,
+    part: u8,
+    answer: impl Display,
+) -> Result<SubmissionOutcome, AocInputError> {
+    submit_answer_with(&AocClient::new(), year, day, part, answer)
+}
+
+/// Like [`submit_answer`], but using the given client instead of the default one.
+pub fn submit_answer_with(
+    client: &AocClient,
+    year: u32,
+    day: u32,
+    part: u8,
+    answer: impl Display,
+) -> Result<SubmissionOutcome, AocInputError> {
+    validate_puzzle(year, day)?;
+    if part != 1 && part != 2 {
+        return Err(AocInputError::NoSuchPart { part });
+    }
+    let session = client.resolve_session()?;
+    client.ensure_unlocked(year, day)?;
+    let response = client.post_form(
+        &format!("/{year}/day/{day}/answer", year = year, day = day),
+        &session,
+        &[("level", part.to_string()), ("answer", answer.to_string())],
+    )?;
+
+    check_status(&response, year, Some(day))?;
+    parse_submission_response(&response.text()?)
+}
+
+/// Works out the [`SubmissionOutcome`] from the HTML page AoC sends back after an answer
+/// is submitted. The interesting part is the text of the page's `<article>`.
+fn parse_submission_response(html: &str) -> Result<SubmissionOutcome, AocInputError> {
+    let article = Regex::new(r"(?s)<article>(.*?)</article>")
+        .unwrap()
+        .captures(html)
+        .map(|caps| caps[1].to_string())
+        .ok_or_else(|| AocInputError::UnexpectedResponse(html.to_string()))?;
+
+    if article.contains("That's the right answer") {
+        Ok(SubmissionOutcome::Correct)
+    } else if article.contains("You don't seem to be solving the right level") {
+        Ok(SubmissionOutcome::AlreadySolved)
+    } else if article.contains("You gave an answer too recently") {
+        let wait = Regex::new(r"(?:(\d+)m )?(\d+)s left to wait")
+            .unwrap()
+            .captures(&article)
+            .map(|caps| {
+                let minutes: u64 = caps.get(1).map_or(0, |m| m.as_str().parse().unwrap());
+                let seconds: u64 = caps[2].parse().unwrap();
+                Duration::from_secs(minutes * 60 + seconds)
+            })
+            .unwrap_or_default();
+        Ok(SubmissionOutcome::RateLimited { wait })
+    } else if article.contains("That's not the right answer") {
+        if article.contains("your answer is too high") {
+            Ok(SubmissionOutcome::TooHigh)
+        } else if article.contains("your answer is too low") {
+            Ok(SubmissionOutcome::TooLow)
+        } else {
+            Ok(SubmissionOutcome::Incorrect)
+        }
+    } else {
+        Err(AocInputError::UnexpectedResponse(article))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(article: &str) -> String {
+        format!(
+            "<html><body><main><article><p>{}</p></article></main></body></html>",
+            article
+        )
+    }
+
+    #[test]
+    fn test_parse_correct() {
+        let html = page("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer to decorating the North Pole.");
+        assert_eq!(
+            parse_submission_response(&html).unwrap(),
+            SubmissionOutcome::Correct
+        );
+    }
+
+    #[test]
+    fn test_parse_too_high_and_too_low() {
+        let high = page("That's not the right answer; your answer is too high.  Please wait one minute before trying again.");
+        let low = page("That's not the right answer; your answer is too low.  Please wait one minute before trying again.");
+        let neither = page("That's not the right answer.  If you're stuck, make sure you're using the full input data.");
+        assert_eq!(
+            parse_submission_response(&high).unwrap(),
+            SubmissionOutcome::TooHigh
+        );
+        assert_eq!(
+            parse_submission_response(&low).unwrap(),
+            SubmissionOutcome::TooLow
+        );
+        assert_eq!(
+            parse_submission_response(&neither).unwrap(),
+            SubmissionOutcome::Incorrect
+        );
+    }
+
+    #[test]
+    fn test_parse_already_solved() {
+        let html =
+            page("You don't seem to be solving the right level.  Did you already complete it?");
+        assert_eq!(
+            parse_submission_response(&html).unwrap(),
+            SubmissionOutcome::AlreadySolved
+        );
+    }
+
+    #[test]
+    fn test_parse_rate_limited() {
+        let seconds = page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 34s left to wait.");
+        let minutes = page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 2s left to wait.");
+        assert_eq!(
+            parse_submission_response(&seconds).unwrap(),
+            SubmissionOutcome::RateLimited {
+                wait: Duration::from_secs(34)
+            }
+        );
+        assert_eq!(
+            parse_submission_response(&minutes).unwrap(),
+            SubmissionOutcome::RateLimited {
+                wait: Duration::from_secs(242)
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_unexpected() {
+        assert!(matches!(
+            parse_submission_response("<html>Not an article in sight</html>"),
+            Err(AocInputError::UnexpectedResponse(_))
+        ));
+    }
+}
@@ -0,0 +1,121 @@
+//! End to end tests of the `aoc` binary, run on the worked examples so they never touch the
+//! network.
+
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Output, Stdio};
+
+fn example(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test-inputs/2025")
+        .join(name)
+}
+
+/// Runs `aoc` with `args`, feeding it `stdin`, in a scratch directory named after the test so
+/// nothing is read from or cached in the checkout.
+fn aoc(name: &str, args: &[&str], stdin: &str) -> Output {
+    let dir = std::env::temp_dir().join(format!("aoc-cli-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(args)
+        .current_dir(&dir)
+        .env_remove("AOC_SESSION")
+        .env("AOC_THROTTLE_FILE", dir.join("last_request"))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    let _ = fs::remove_dir_all(&dir);
+    output
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+fn stderr(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stderr).into_owned()
+}
+
+#[test]
+fn test_run_with_input_file() {
+    let path = example("day_1.txt");
+    let output = aoc(
+        "file",
+        &["run", "--day", "1", "--input", path.to_str().unwrap()],
+        "",
+    );
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(stdout(&output), "Day 1! Part 1: 3, Part 2: 6\n");
+}
+
+#[test]
+fn test_run_one_part_from_stdin() {
+    let input = fs::read_to_string(example("day_1.txt")).unwrap();
+    let output = aoc(
+        "stdin",
+        &["run", "--day", "1", "--part", "2", "--input", "-"],
+        &input,
+    );
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(stdout(&output), "Day 1 part 2: 6\n");
+}
+
+#[test]
+fn test_run_json() {
+    let path = example("day_5.txt");
+    let output = aoc(
+        "json",
+        &[
+            "run",
+            "--day",
+            "5",
+            "--format",
+            "json",
+            "--input",
+            path.to_str().unwrap(),
+        ],
+        "",
+    );
+    assert!(output.status.success(), "{}", stderr(&output));
+    let lines: Vec<_> = stdout(&output).lines().map(str::to_string).collect();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].starts_with(r#"{"year":2025,"day":5,"part":1,"answer":"3","status":"solved""#));
+    assert!(
+        lines[1].starts_with(r#"{"year":2025,"day":5,"part":2,"answer":"14","status":"solved""#)
+    );
+}
+
+#[test]
+fn test_missing_input_file_is_named() {
+    let output = aoc(
+        "missing",
+        &["run", "--day", "1", "--input", "missing.txt"],
+        "",
+    );
+    assert_eq!(output.status.code(), Some(1));
+    assert!(
+        stderr(&output).contains("missing.txt"),
+        "{}",
+        stderr(&output)
+    );
+}
+
+#[test]
+fn test_usage_errors_exit_2() {
+    for args in [&["bogus"][..], &["run", "--day", "0"], &["run", "--input"]] {
+        let output = aoc("usage", args, "");
+        assert_eq!(output.status.code(), Some(2), "{:?}", args);
+        assert!(stderr(&output).contains("Usage: aoc"), "{:?}", args);
+    }
+}
@@ -0,0 +1,239 @@
+//! End to end tests of fetching, caching and submitting against a local stand-in for the
+//! Advent of Code website.
+
+mod common;
+
+use std::fs;
+use std::time::Duration;
+
+use aoc_2025::utils::client::DEFAULT_USER_AGENT;
+use aoc_2025::utils::error::AocInputError;
+use aoc_2025::utils::get_input::{get_aoc_input_with, refresh_aoc_input_with};
+use aoc_2025::utils::leaderboard::fetch_leaderboard_with;
+use aoc_2025::utils::puzzle::fetch_puzzle_page_with;
+use aoc_2025::utils::submit::{submit_answer_with, SubmissionOutcome};
+use common::{article, MockAoc, Request, Workspace, TOKEN};
+
+/// Behaves like the parts of the AoC website we use, for a user logged in with [`TOKEN`].
+fn website(request: &Request) -> (u16, String) {
+    let logged_in = request.headers.get("cookie") == Some(&format!("session={}", TOKEN));
+    match (request.method.as_str(), request.path.as_str()) {
+        ("GET", "/2015/day/1") => (
+            200,
+            "<main><article class=\"day-desc\"><h2>--- Day 1: Not Quite Lisp ---</h2>\
+             <p>For example:</p><pre><code>(())\n</code></pre></article></main>"
+                .to_string(),
+        ),
+        _ if !logged_in => (
+            400,
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.".to_string(),
+        ),
+        ("GET", "/2015/day/1/input") => (200, "(()(()(\n".to_string()),
+        ("GET", "/2015/day/2/input") => (
+            404,
+            "Please don't repeatedly request this endpoint before it unlocks!".to_string(),
+        ),
+        ("POST", "/2015/day/1/answer") => match request.body.as_str() {
+            "level=1&answer=3" => (200, article("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer.")),
+            "level=1&answer=100" => (200, article("That's not the right answer; your answer is too high.  Please wait one minute before trying again.")),
+            "level=2&answer=1" => (200, article("You don't seem to be solving the right level.  Did you already complete it?")),
+            _ => (200, article("That's not the right answer.  If you're stuck, make sure you're using the full input data.")),
+        },
+        ("POST", "/2015/day/2/answer") => (
+            200,
+            article("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 30s left to wait."),
+        ),
+        ("GET", "/2015/leaderboard/private/view/1.json") => (
+            200,
+            r#"{"event":"2015","owner_id":1,"members":{"1":{"id":1,"name":"Santa","stars":1,
+               "local_score":1,"last_star_ts":1449032400,"completion_day_level":
+               {"1":{"1":{"get_star_ts":1449032400,"star_index":0}}}}}}"#
+                .to_string(),
+        ),
+        ("GET", "/2015/leaderboard/private/view/2.json") => (
+            200,
+            "<html>You are not a member of this leaderboard</html>".to_string(),
+        ),
+        _ => (404, "404 Not Found".to_string()),
+    }
+}
+
+#[test]
+fn test_fetches_input_then_uses_cache() {
+    let server = MockAoc::start(website);
+    let workspace = Workspace::new("fetch");
+    let client = workspace.client(&server);
+    let cache = workspace.cache();
+
+    assert_eq!(
+        get_aoc_input_with(&client, &cache, 2015, 1).unwrap(),
+        "(()(()(\n"
+    );
+    assert_eq!(
+        fs::read_to_string(cache.path_for(2015, 1)).unwrap(),
+        "(()(()(\n"
+    );
+    assert_eq!(
+        get_aoc_input_with(&client, &cache, 2015, 1).unwrap(),
+        "(()(()(\n"
+    );
+
+    let requests = server.requests();
+    assert_eq!(
+        requests.len(),
+        1,
+        "second call should be served from the cache"
+    );
+    assert_eq!(requests[0].path, "/2015/day/1/input");
+    assert_eq!(
+        requests[0].headers.get("user-agent").map(String::as_str),
+        Some(DEFAULT_USER_AGENT)
+    );
+}
+
+#[test]
+fn test_refresh_ignores_cache() {
+    let server = MockAoc::start(website);
+    let workspace = Workspace::new("refresh");
+    let cache = workspace.cache();
+    cache.write(2015, 1, "stale").unwrap();
+
+    assert_eq!(
+        refresh_aoc_input_with(&workspace.client(&server), &cache, 2015, 1).unwrap(),
+        "(()(()(\n"
+    );
+    assert_eq!(cache.read(2015, 1).unwrap(), "(()(()(\n");
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn test_not_unlocked() {
+    let server = MockAoc::start(website);
+    let workspace = Workspace::new("not-unlocked");
+
+    let result = get_aoc_input_with(&workspace.client(&server), &workspace.cache(), 2015, 2);
+    assert!(matches!(
+        result,
+        Err(AocInputError::NotUnlocked { year: 2015, day: 2 })
+    ));
+    assert_eq!(workspace.cache().read(2015, 2), None);
+}
+
+#[test]
+fn test_impossible_puzzle_sends_nothing() {
+    let server = MockAoc::start(website);
+    let workspace = Workspace::new("no-such-puzzle");
+    let client = workspace.client(&server);
+
+    assert!(matches!(
+        get_aoc_input_with(&client, &workspace.cache(), 2025, 13),
+        Err(AocInputError::NoSuchPuzzle {
+            year: 2025,
+            day: 13
+        })
+    ));
+    assert!(matches!(
+        submit_answer_with(&client, 2014, 1, 1, 3),
+        Err(AocInputError::NoSuchPuzzle { .. })
+    ));
+    assert!(matches!(
+        submit_answer_with(&client, 2025, 1, 3, 3),
+        Err(AocInputError::NoSuchPart { part: 3 })
+    ));
+    assert!(server.requests().is_empty());
+}
+
+#[test]
+fn test_bad_session() {
+    let server = MockAoc::start(website);
+    let workspace = Workspace::new("bad-session");
+    fs::write(workspace.dir.join("cookie.txt"), TOKEN.replace('0', "f")).unwrap();
+
+    let result = get_aoc_input_with(&workspace.client(&server), &workspace.cache(), 2015, 1);
+    assert!(matches!(result, Err(AocInputError::ExpiredSession)));
+}
+
+#[test]
+fn test_missing_session_sends_nothing() {
+    let server = MockAoc::start(website);
+    let workspace = Workspace::new("no-session");
+    fs::remove_file(workspace.dir.join("cookie.txt")).unwrap();
+
+    let result = get_aoc_input_with(&workspace.client(&server), &workspace.cache(), 2015, 1);
+    assert!(matches!(result, Err(AocInputError::NoSession)));
+    assert!(server.requests().is_empty());
+}
+
+#[test]
+fn test_submit_answers() {
+    let server = MockAoc::start(website);
+    let workspace = Workspace::new("submit");
+    let client = workspace.client(&server);
+
+    let outcomes = [
+        submit_answer_with(&client, 2015, 1, 1, 3).unwrap(),
+        submit_answer_with(&client, 2015, 1, 1, 100).unwrap(),
+        submit_answer_with(&client, 2015, 1, 1, 7).unwrap(),
+        submit_answer_with(&client, 2015, 1, 2, 1).unwrap(),
+        submit_answer_with(&client, 2015, 2, 1, 3).unwrap(),
+    ];
+    assert_eq!(
+        outcomes,
+        [
+            SubmissionOutcome::Correct,
+            SubmissionOutcome::TooHigh,
+            SubmissionOutcome::Incorrect,
+            SubmissionOutcome::AlreadySolved,
+            SubmissionOutcome::RateLimited {
+                wait: Duration::from_secs(90)
+            },
+        ]
+    );
+
+    let first = &server.requests()[0];
+    assert_eq!(first.method, "POST");
+    assert_eq!(first.body, "level=1&answer=3");
+}
+
+#[test]
+fn test_fetch_puzzle_page() {
+    let server = MockAoc::start(website);
+    let workspace = Workspace::new("puzzle");
+
+    let page = fetch_puzzle_page_with(&workspace.client(&server), 2015, 1).unwrap();
+    assert_eq!(page.examples()[0].text, "(())\n");
+    assert!(page
+        .to_markdown()
+        .starts_with("## --- Day 1: Not Quite Lisp ---"));
+}
+
+#[test]
+fn test_leaderboard_is_cached() {
+    let server = MockAoc::start(website);
+    let workspace = Workspace::new("leaderboard");
+    let client = workspace.client(&server);
+    let cache = workspace.leaderboards();
+
+    let leaderboard = fetch_leaderboard_with(&client, &cache, 2015, 1).unwrap();
+    assert_eq!(leaderboard.members[&1].display_name(), "Santa");
+    assert!(leaderboard.render().contains("Santa (1 stars)"));
+    fetch_leaderboard_with(&client, &cache, 2015, 1).unwrap();
+    assert_eq!(server.requests().len(), 1);
+
+    // Purging the year's inputs leaves the leaderboard be
+    workspace.cache().purge_year(2015).unwrap();
+    fetch_leaderboard_with(&client, &cache, 2015, 1).unwrap();
+    assert_eq!(server.requests().len(), 1);
+
+    // Each profile has its own copy
+    fetch_leaderboard_with(&client, &cache.clone().profile("alt"), 2015, 1).unwrap();
+    assert_eq!(server.requests().len(), 2);
+    assert!(cache.path_for(2015, 1).exists());
+    assert!(cache.clone().profile("alt").path_for(2015, 1).exists());
+
+    assert!(matches!(
+        fetch_leaderboard_with(&client, &cache, 2015, 2),
+        Err(AocInputError::UnexpectedResponse(_))
+    ));
+    assert!(!cache.path_for(2015, 2).exists());
+}
@@ -0,0 +1,158 @@
+//! A tiny stand-in for the Advent of Code website, so the networking code can be tested
+//! without the internet.
+
+use std::collections::HashMap;
+use std::fs;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use aoc_2025::utils::cache::InputCache;
+use aoc_2025::utils::client::AocClient;
+use aoc_2025::utils::leaderboard::LeaderboardCache;
+use aoc_2025::utils::session::SessionResolver;
+
+/// A syntactically valid session token, which the mock server expects to be sent.
+pub const TOKEN: &str = "0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef";
+
+/// A request as seen by the mock server.
+#[derive(Debug, Clone)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub headers: HashMap<String, String>,
+    pub body: String,
+}
+
+/// What the mock server sends back for a request: a status code and a body.
+pub type Route = fn(&Request) -> (u16, String);
+
+/// A local HTTP server answering requests with a fixed routing function.
+pub struct MockAoc {
+    pub base_url: String,
+    requests: Arc<Mutex<Vec<Request>>>,
+}
+
+impl MockAoc {
+    /// Starts a server on a free local port, answering every request with `route`.
+    pub fn start(route: Route) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let seen = Arc::clone(&requests);
+        thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let request = read_request(&stream);
+                seen.lock().unwrap().push(request.clone());
+                write_response(stream, route(&request));
+            }
+        });
+
+        MockAoc { base_url, requests }
+    }
+
+    /// Every request the server has received so far.
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+/// A scratch directory holding a cookie file and an input cache, unique to one test.
+pub struct Workspace {
+    pub dir: PathBuf,
+}
+
+impl Workspace {
+    pub fn new(name: &str) -> Self {
+        let dir = std::env::temp_dir().join(format!("aoc-it-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("cookie.txt"), TOKEN).unwrap();
+        Workspace { dir }
+    }
+
+    /// A client pointed at the mock server, using this workspace's cookie, with no throttling.
+    pub fn client(&self, server: &MockAoc) -> AocClient {
+        AocClient::new()
+            .base_url(&server.base_url)
+            .session(
+                SessionResolver::new()
+                    .env_var("AOC_SESSION_INTEGRATION_TESTS_UNSET")
+                    .project_dir(&self.dir)
+                    .config_dir(&self.dir),
+            )
+            .min_interval(Duration::ZERO)
+            .throttle_file(self.dir.join(".last_request"))
+    }
+
+    pub fn cache(&self) -> InputCache {
+        InputCache::new(self.dir.join("inputs"))
+    }
+
+    pub fn leaderboards(&self) -> LeaderboardCache {
+        LeaderboardCache::new(self.dir.join("leaderboards"))
+    }
+}
+
+impl Drop for Workspace {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.dir);
+    }
+}
+
+/// Wraps some text in the bits of an AoC page that the parsers look for.
+pub fn article(text: &str) -> String {
+    format!(
+        "<html><body><main><article><p>{}</p></article></main></body></html>",
+        text
+    )
+}
+
+fn read_request(stream: &TcpStream) -> Request {
+    let mut reader = BufReader::new(stream);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line).unwrap();
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or_default().to_string();
+    let path = parts.next().unwrap_or_default().to_string();
+
+    let mut headers = HashMap::new();
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line).unwrap();
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            headers.insert(name.trim().to_lowercase(), value.trim().to_string());
+        }
+    }
+
+    let length: usize = headers
+        .get("content-length")
+        .and_then(|length| length.parse().ok())
+        .unwrap_or(0);
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).unwrap();
+
+    Request {
+        method,
+        path,
+        headers,
+        body: String::from_utf8(body).unwrap(),
+    }
+}
+
+fn write_response(mut stream: TcpStream, (status, body): (u16, String)) {
+    let response = format!(
+        "HTTP/1.1 {} Mock\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    );
+    stream.write_all(response.as_bytes()).unwrap();
+}